@@ -7,7 +7,7 @@ use std::fmt;
 use crate::validation::Checked;
 
 /// The component data type.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize)]
 pub enum ComponentType {
     /// Corresponds to `GL_BYTE`.
     I8 = 1,
@@ -29,7 +29,7 @@ pub enum ComponentType {
 }
 
 /// Specifies whether an attribute, vector, or matrix.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize)]
 pub enum Type {
     /// Scalar quantity.
     Scalar = 1,
@@ -115,7 +115,7 @@ pub mod sparse {
         pub buffer_view: Index<buffer::View>,
 
         /// The offset relative to the start of the parent `BufferView` in bytes.
-        #[serde(default, rename = "byteOffset")]
+        #[serde(default, rename = "byteOffset", skip_serializing_if = "super::is_byte_offset_default")]
         pub byte_offset: u32,
 
         /// The data type of each index.
@@ -128,7 +128,7 @@ pub mod sparse {
 
         /// Optional application specific data.
         #[serde(default)]
-        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[serde(skip_serializing_if = "crate::extras::is_empty")]
         pub extras: Extras,
     }
 
@@ -157,7 +157,7 @@ pub mod sparse {
 
         /// Optional application specific data.
         #[serde(default)]
-        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[serde(skip_serializing_if = "crate::extras::is_empty")]
         pub extras: Extras,
     }
 
@@ -173,7 +173,7 @@ pub mod sparse {
         pub buffer_view: Index<buffer::View>,
 
         /// The offset relative to the start of the parent buffer view in bytes.
-        #[serde(default, rename = "byteOffset")]
+        #[serde(default, rename = "byteOffset", skip_serializing_if = "super::is_byte_offset_default")]
         pub byte_offset: u32,
 
         /// Extension specific data.
@@ -182,7 +182,7 @@ pub mod sparse {
 
         /// Optional application specific data.
         #[serde(default)]
-        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[serde(skip_serializing_if = "crate::extras::is_empty")]
         pub extras: Extras,
     }
 }
@@ -195,7 +195,7 @@ pub struct Accessor {
     pub buffer_view: Index<buffer::View>,
 
     /// The offset relative to the start of the parent `BufferView` in bytes.
-    #[serde(default, rename = "byteOffset")]
+    #[serde(default, rename = "byteOffset", skip_serializing_if = "is_byte_offset_default")]
     pub byte_offset: u32,
 
     /// The number of components within the buffer view - not to be confused
@@ -212,7 +212,7 @@ pub struct Accessor {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 
     /// Specifies if the attribute is a scalar, vector, or matrix.
@@ -230,7 +230,7 @@ pub struct Accessor {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Name>,
 
     /// Specifies whether integer data values should be normalized.
     #[serde(default, skip_serializing_if = "is_normalized_default")]
@@ -248,6 +248,26 @@ fn is_normalized_default(b: &bool) -> bool {
     !*b
 }
 
+// Help serde avoid serializing this glTF 2.0 default value.
+fn is_byte_offset_default(offset: &u32) -> bool {
+    *offset == 0
+}
+
+impl Accessor {
+    /// Returns the size of each element in bytes, excluding any padding
+    /// introduced by `byteStride`.
+    ///
+    /// Returns `None` if the component type or dimensions are invalid.
+    pub fn element_size(&self) -> Option<usize> {
+        match (&self.component_type, &self.type_) {
+            (&Checked::Valid(GenericComponentType(component_type)), &Checked::Valid(type_)) => {
+                Some(component_type.size() * type_.multiplicity())
+            },
+            _ => None,
+        }
+    }
+}
+
 /// The data type of an index.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct IndexComponentType(pub ComponentType);
@@ -71,6 +71,10 @@ pub enum Property {
     Scale,
 
     /// Weights of morph targets.
+    ///
+    /// Unlike the other properties this does not affect the node transform,
+    /// but the `weights` of the node, or those of its mesh when the node has
+    /// none.
     MorphTargetWeights,
 }
 
@@ -83,7 +87,7 @@ pub struct Animation {
     
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
     
     /// An array of channels, each of which targets an animation's sampler at a
@@ -96,7 +100,7 @@ pub struct Animation {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Name>,
     
     /// An array of samplers that combine input and output accessors with an
     /// interpolation algorithm to define a keyframe graph (but not its target).
@@ -120,7 +124,7 @@ pub struct Channel {
     
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
@@ -133,7 +137,7 @@ pub struct Target {
     
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
     
     /// The index of the node to target.
@@ -153,20 +157,30 @@ pub struct Sampler {
     
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
     
     /// The index of an accessor containing keyframe input values, e.g., time.
     pub input: Index<accessor::Accessor>,
     
     /// The interpolation algorithm.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_interpolation_default")]
     pub interpolation: Checked<Interpolation>,
     
     /// The index of an accessor containing keyframe output values.
     pub output: Index<accessor::Accessor>,
 }
 
+impl Animation {
+    /// Returns a sampler of this animation.
+    ///
+    /// Channel sampler indices refer to the animation's own samplers rather
+    /// than to the texture samplers of the root object.
+    pub fn sampler(&self, index: Index<Sampler>) -> Option<&Sampler> {
+        self.samplers.get(index.value())
+    }
+}
+
 impl Validate for Animation {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
     where
@@ -177,12 +191,22 @@ impl Validate for Animation {
         for (index, channel) in self.channels.iter().enumerate() {
             if channel.sampler.value() as usize >= self.samplers.len() {
                 let path = || path().field("channels").index(index).field("sampler");
-                report(&path, Error::IndexOutOfBounds);
+                report(&path, Error::IndexOutOfBounds { len: self.samplers.len() });
             }
+            channel.target.validate(
+                root,
+                || path().field("channels").index(index).field("target"),
+                report,
+            );
         }
     }
 }
 
+// Help serde avoid serializing this glTF 2.0 default value.
+fn is_interpolation_default(interpolation: &Checked<Interpolation>) -> bool {
+    *interpolation == Checked::Valid(Interpolation::Linear)
+}
+
 impl Default for Interpolation {
     fn default() -> Self {
         Interpolation::Linear
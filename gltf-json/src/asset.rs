@@ -1,9 +1,9 @@
-use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
-use crate::{extensions, Extras};
+use crate::validation::{Error, Validate};
+use crate::{extensions, Extras, Path, Root};
 
 /// Metadata about the glTF asset.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Asset {
     /// A copyright message suitable for display to credit the content creator.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -15,7 +15,7 @@ pub struct Asset {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 
     /// Tool that generated this glTF model.
@@ -44,3 +44,47 @@ impl Default for Asset {
     }
 }
 
+impl Validate for Asset {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        let version = parse_version(&self.version);
+        if version.is_none() {
+            report(&|| path().field("version"), Error::InvalidVersion);
+        }
+        if let Some(min_version) = self.min_version.as_ref() {
+            // spec: minVersion must not be greater than version.
+            match (parse_version(min_version), version) {
+                (None, _) => report(&|| path().field("minVersion"), Error::InvalidVersion),
+                (Some(min_version), Some(version)) if min_version > version => {
+                    report(&|| path().field("minVersion"), Error::Invalid);
+                },
+                _ => {},
+            }
+        }
+
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+    }
+}
+
+/// Parses a glTF version string of the form `major.minor` into its numbers.
+///
+/// A bare `major` version is accepted with a minor version of zero.
+/// Returns `None` if the string is malformed.
+pub fn parse_version(version: &str) -> Option<(u32, u32)> {
+    fn number(s: &str) -> Option<u32> {
+        if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            s.parse().ok()
+        } else {
+            None
+        }
+    }
+    let mut parts = version.splitn(2, '.');
+    let major = number(parts.next()?)?;
+    let minor = match parts.next() {
+        Some(minor) => number(minor)?,
+        None => 0,
+    };
+    Some((major, minor))
+}
@@ -30,7 +30,7 @@ pub struct Camera {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Name>,
 
     /// An orthographic camera containing properties to create an orthographic
     /// projection matrix.
@@ -52,7 +52,7 @@ pub struct Camera {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
@@ -77,7 +77,7 @@ pub struct Orthographic {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
@@ -105,7 +105,7 @@ pub struct Perspective {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
@@ -113,8 +113,18 @@ impl Validate for Camera {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
         where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
     {
-        if self.orthographic.is_none() && self.perspective.is_none() {
-            report(&path, Error::Missing);
+        // The projection block named by `type` must be present.
+        match self.type_ {
+            Checked::Valid(Type::Orthographic) if self.orthographic.is_none() => {
+                report(&|| path().field("orthographic"), Error::Missing);
+            },
+            Checked::Valid(Type::Perspective) if self.perspective.is_none() => {
+                report(&|| path().field("perspective"), Error::Missing);
+            },
+            _ if self.orthographic.is_none() && self.perspective.is_none() => {
+                report(&path, Error::Missing);
+            },
+            _ => {},
         }
 
         self.orthographic.validate(root, || path().field("orthographic"), report);
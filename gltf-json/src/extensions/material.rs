@@ -63,7 +63,7 @@ pub struct PbrSpecularGlossiness {
     pub specular_glossiness_texture: Option<texture::Info>,
 
     /// Optional application specific data.
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
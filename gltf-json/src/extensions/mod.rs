@@ -38,14 +38,20 @@ pub use self::root::Root;
 
 /// Names of glTF 2.0 extensions enabled by the user.
 pub const ENABLED_EXTENSIONS: &'static [&'static str] = &[
+    #[cfg(feature = "EXT_texture_avif")]
+    "EXT_texture_avif",
     #[cfg(feature = "KHR_lights_punctual")]
     "KHR_lights_punctual",
     #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
     "KHR_materials_pbrSpecularGlossiness",
+    #[cfg(feature = "KHR_texture_transform")]
+    "KHR_texture_transform",
 ];
 
 /// Names of glTF 2.0 extensions supported by the library.
 pub const SUPPORTED_EXTENSIONS: &'static [&'static str] = &[
+    "EXT_texture_avif",
     "KHR_lights_punctual",
     "KHR_materials_pbrSpecularGlossiness",
+    "KHR_texture_transform",
 ];
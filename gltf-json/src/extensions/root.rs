@@ -31,4 +31,11 @@ impl crate::root::Get<crate::extensions::scene::khr_lights_punctual::Light> for
             None
         }
     }
+
+    fn len(&self) -> usize {
+        self.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_lights_punctual.as_ref())
+            .map_or(0, |khr_lights_punctual| khr_lights_punctual.lights.len())
+    }
 }
@@ -82,7 +82,7 @@ pub mod khr_lights_punctual {
 
         /// Optional application specific data.
         #[serde(default)]
-        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        #[serde(skip_serializing_if = "crate::extras::is_empty")]
         pub extras: Extras,
 
         /// Intensity of the light source. `point` and `spot` lights use luminous intensity
@@ -93,7 +93,7 @@ pub mod khr_lights_punctual {
         /// Optional user-defined name for this object.
         #[cfg(feature = "names")]
         #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-        pub name: Option<String>,
+        pub name: Option<crate::Name>,
 
         /// A distance cutoff at which the light's intensity may be considered to have reached
         /// zero.
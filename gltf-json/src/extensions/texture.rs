@@ -1,5 +1,9 @@
 use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
+#[cfg(feature = "EXT_texture_avif")]
+use crate::{image, Index};
+#[cfg(feature = "KHR_texture_transform")]
+use crate::{Extras, validation::Validate};
 
 /// Texture sampler properties for filtering and wrapping modes.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
@@ -7,8 +11,74 @@ pub struct Sampler {}
 
 /// A texture and its sampler.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Texture {}
+pub struct Texture {
+    #[cfg(feature = "EXT_texture_avif")]
+    #[serde(default, rename = "EXT_texture_avif", skip_serializing_if = "Option::is_none")]
+    pub ext_texture_avif: Option<ExtTextureAvif>,
+}
+
+/// Points a texture at an AVIF encoded image.
+#[cfg(feature = "EXT_texture_avif")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct ExtTextureAvif {
+    /// The index of the AVIF image used by this texture.
+    pub source: Index<image::Image>,
+}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 /// Reference to a `Texture`.
-pub struct Info {}
+pub struct Info {
+    #[cfg(feature = "KHR_texture_transform")]
+    #[serde(default, rename = "KHR_texture_transform", skip_serializing_if = "Option::is_none")]
+    pub texture_transform: Option<TextureTransform>,
+}
+
+/// Transforms the texture co-ordinates of a texture reference.
+///
+/// The transform is applied to the UVs as scale, then rotation, then offset.
+#[cfg(feature = "KHR_texture_transform")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TextureTransform {
+    /// The offset of the UV co-ordinate origin as a factor of the texture
+    /// dimensions.
+    pub offset: TextureTransformOffset,
+
+    /// Rotates the UVs by this many radians counter-clockwise around the
+    /// origin.
+    pub rotation: f32,
+
+    /// The scale factor applied to the components of the UV co-ordinates.
+    pub scale: TextureTransformScale,
+
+    /// Overrides the `texCoord` of the texture reference if supplied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tex_coord: Option<u32>,
+
+    /// Optional application specific data.
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
+    pub extras: Extras,
+}
+
+/// The offset of the UV co-ordinate origin of a texture transform.
+#[cfg(feature = "KHR_texture_transform")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct TextureTransformOffset(pub [f32; 2]);
+
+#[cfg(feature = "KHR_texture_transform")]
+impl Validate for TextureTransformOffset {}
+
+/// The scale factor of the UV co-ordinates of a texture transform.
+#[cfg(feature = "KHR_texture_transform")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct TextureTransformScale(pub [f32; 2]);
+
+#[cfg(feature = "KHR_texture_transform")]
+impl Default for TextureTransformScale {
+    fn default() -> Self {
+        TextureTransformScale([1.0, 1.0])
+    }
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+impl Validate for TextureTransformScale {}
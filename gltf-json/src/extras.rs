@@ -13,6 +13,32 @@ pub type Extras = Option<::std::boxed::Box<RawValue>>;
 #[cfg(not(feature = "extras"))]
 pub type Extras = Void;
 
+/// Returns `true` if there is no user-defined data to serialize.
+#[cfg(feature = "extras")]
+pub(crate) fn is_empty(extras: &Extras) -> bool {
+    extras.is_none()
+}
+
+/// Returns `true` if there is no user-defined data to serialize.
+#[cfg(not(feature = "extras"))]
+pub(crate) fn is_empty(_: &Extras) -> bool {
+    true
+}
+
+/// Deserializes the `extras` attribute of a glTF object into a
+/// user-defined type.
+///
+/// Returns `Ok(None)` when the object has no extras.
+#[cfg(feature = "extras")]
+pub fn extras_as<T>(extras: &Extras) -> Result<Option<T>, serde_json::Error>
+    where T: serde::de::DeserializeOwned
+{
+    match extras.as_ref() {
+        Some(raw) => serde_json::from_str(raw.get()).map(Some),
+        None => Ok(None),
+    }
+}
+
 /// Type representing no user-defined data.
 #[derive(Clone, Default, Serialize, Deserialize, Validate)]
 pub struct Void {
@@ -81,6 +81,24 @@ pub use self::root::Index;
 #[doc(inline)]
 pub use self::root::Root;
 
+/// The type of the optional user-defined names of glTF objects.
+///
+/// With the `intern_names` feature this is a shared `Arc<str>`, so that
+/// [`Root::intern_names`] can make identical names share storage.
+///
+/// [`Root::intern_names`]: struct.Root.html#method.intern_names
+#[cfg(not(feature = "intern_names"))]
+pub type Name = String;
+
+/// The type of the optional user-defined names of glTF objects.
+///
+/// With the `intern_names` feature this is a shared `Arc<str>`, so that
+/// [`Root::intern_names`] can make identical names share storage.
+///
+/// [`Root::intern_names`]: struct.Root.html#method.intern_names
+#[cfg(feature = "intern_names")]
+pub type Name = std::sync::Arc<str>;
+
 #[doc(inline)]
 pub use serde_json::Error;
 #[doc(inline)]
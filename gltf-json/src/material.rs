@@ -44,7 +44,7 @@ impl ser::Serialize for AlphaMode {
 #[serde(default)]
 pub struct Material {
     /// The alpha cutoff value of the material.
-    #[serde(rename = "alphaCutoff")]
+    #[serde(rename = "alphaCutoff", skip_serializing_if = "is_alpha_cutoff_default")]
     pub alpha_cutoff: AlphaCutoff,
     
     /// The alpha rendering mode of the material.
@@ -63,7 +63,7 @@ pub struct Material {
     ///   destination areas and the rendered output is combined with the
     ///   background using the normal painting operation (i.e. the Porter and
     ///   Duff over operator).
-    #[serde(rename = "alphaMode")]
+    #[serde(rename = "alphaMode", skip_serializing_if = "is_alpha_mode_default")]
     pub alpha_mode: Checked<AlphaMode>,
 
     /// Specifies whether the material is double-sided.
@@ -75,18 +75,18 @@ pub struct Material {
     ///
     /// The back-face must have its normals reversed before the lighting
     /// equation is evaluated.
-    #[serde(rename = "doubleSided")]
+    #[serde(rename = "doubleSided", skip_serializing_if = "is_double_sided_default")]
     pub double_sided: bool,
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Name>,
 
     /// A set of parameter values that are used to define the metallic-roughness
     /// material model from Physically-Based Rendering (PBR) methodology. When not
     /// specified, all the default values of `pbrMetallicRoughness` apply.
-    #[serde(default, rename = "pbrMetallicRoughness")]
+    #[serde(default, rename = "pbrMetallicRoughness", skip_serializing_if = "is_pbr_metallic_roughness_default")]
     pub pbr_metallic_roughness: PbrMetallicRoughness,
 
     /// A tangent space normal map. The texture contains RGB components in linear
@@ -116,7 +116,7 @@ pub struct Material {
     pub emissive_texture: Option<texture::Info>,
 
     /// The emissive color of the material.
-    #[serde(rename = "emissiveFactor")]
+    #[serde(rename = "emissiveFactor", skip_serializing_if = "is_emissive_factor_default")]
     pub emissive_factor: EmissiveFactor,
 
     /// Extension specific data.
@@ -124,7 +124,7 @@ pub struct Material {
     pub extensions: Option<extensions::material::Material>,
 
     /// Optional application specific data.
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
@@ -134,7 +134,7 @@ pub struct Material {
 #[serde(default)]
 pub struct PbrMetallicRoughness {
     /// The material's base color factor.
-    #[serde(rename = "baseColorFactor")]
+    #[serde(rename = "baseColorFactor", skip_serializing_if = "is_base_color_factor_default")]
     pub base_color_factor: PbrBaseColorFactor,
 
     /// The base color texture.
@@ -143,14 +143,14 @@ pub struct PbrMetallicRoughness {
     pub base_color_texture: Option<texture::Info>,
 
     /// The metalness of the material.
-    #[serde(rename = "metallicFactor")]
+    #[serde(rename = "metallicFactor", skip_serializing_if = "is_strength_factor_default")]
     pub metallic_factor: StrengthFactor,
 
     /// The roughness of the material.
     ///
     /// * A value of 1.0 means the material is completely rough.
     /// * A value of 0.0 means the material is completely smooth.
-    #[serde(rename = "roughnessFactor")]
+    #[serde(rename = "roughnessFactor", skip_serializing_if = "is_strength_factor_default")]
     pub roughness_factor: StrengthFactor,
 
     /// The metallic-roughness texture.
@@ -170,7 +170,7 @@ pub struct PbrMetallicRoughness {
     pub extensions: Option<extensions::material::PbrMetallicRoughness>,
 
     /// Optional application specific data.
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
@@ -183,11 +183,11 @@ pub struct NormalTexture {
     /// The scalar multiplier applied to each normal vector of the texture.
     ///
     /// This value is ignored if normalTexture is not specified.
-    #[serde(default = "material_normal_texture_scale_default")]
+    #[serde(default = "material_normal_texture_scale_default", skip_serializing_if = "is_normal_texture_scale_default")]
     pub scale: f32,
 
     /// The set index of the texture's `TEXCOORD` attribute.
-    #[serde(default, rename = "texCoord")]
+    #[serde(default, rename = "texCoord", skip_serializing_if = "texture::is_tex_coord_default")]
     pub tex_coord: u32,
 
     /// Extension specific data.
@@ -196,7 +196,7 @@ pub struct NormalTexture {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
@@ -204,6 +204,45 @@ fn material_normal_texture_scale_default() -> f32 {
     1.0
 }
 
+// Help serde avoid serializing these glTF 2.0 default values.
+fn is_alpha_cutoff_default(cutoff: &AlphaCutoff) -> bool {
+    cutoff.0 == AlphaCutoff::default().0
+}
+
+fn is_alpha_mode_default(mode: &Checked<AlphaMode>) -> bool {
+    *mode == Checked::Valid(AlphaMode::Opaque)
+}
+
+fn is_double_sided_default(double_sided: &bool) -> bool {
+    !*double_sided
+}
+
+fn is_emissive_factor_default(factor: &EmissiveFactor) -> bool {
+    factor.0 == EmissiveFactor::default().0
+}
+
+fn is_base_color_factor_default(factor: &PbrBaseColorFactor) -> bool {
+    factor.0 == PbrBaseColorFactor::default().0
+}
+
+fn is_strength_factor_default(factor: &StrengthFactor) -> bool {
+    factor.0 == StrengthFactor::default().0
+}
+
+fn is_normal_texture_scale_default(scale: &f32) -> bool {
+    *scale == material_normal_texture_scale_default()
+}
+
+fn is_pbr_metallic_roughness_default(pbr: &PbrMetallicRoughness) -> bool {
+    is_base_color_factor_default(&pbr.base_color_factor)
+        && pbr.base_color_texture.is_none()
+        && is_strength_factor_default(&pbr.metallic_factor)
+        && is_strength_factor_default(&pbr.roughness_factor)
+        && pbr.metallic_roughness_texture.is_none()
+        && pbr.extensions.is_none()
+        && crate::extras::is_empty(&pbr.extras)
+}
+
 /// Defines the occlusion texture of a material.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct OcclusionTexture {
@@ -211,11 +250,11 @@ pub struct OcclusionTexture {
     pub index: Index<texture::Texture>,
 
     /// The scalar multiplier controlling the amount of occlusion applied.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_strength_factor_default")]
     pub strength: StrengthFactor,
 
     /// The set index of the texture's `TEXCOORD` attribute.
-    #[serde(default, rename = "texCoord")]
+    #[serde(default, rename = "texCoord", skip_serializing_if = "texture::is_tex_coord_default")]
     pub tex_coord: u32,
 
     /// Extension specific data.
@@ -224,7 +263,7 @@ pub struct OcclusionTexture {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
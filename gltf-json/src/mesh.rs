@@ -2,8 +2,8 @@ use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
 use serde::{de, ser};
 use serde_json::from_value;
-use std::collections::HashMap;
-use std::fmt;
+use std::collections::BTreeMap;
+use std::{fmt, str};
 use crate::validation::{Checked, Error, Validate};
 use crate::{accessor, extensions, material, Extras, Index};
 
@@ -75,7 +75,7 @@ pub enum Mode {
 ///
 /// A node can contain one or more meshes and its transform places the meshes in
 /// the scene.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Mesh {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -83,13 +83,13 @@ pub struct Mesh {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Name>,
 
     /// Defines the geometry to be renderered with a material.
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -100,12 +100,40 @@ pub struct Mesh {
     pub weights: Option<Vec<f32>>,
 }
 
+impl Validate for Mesh {
+    fn validate<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
+        where
+        P: Fn() -> crate::Path,
+        R: FnMut(&dyn Fn() -> crate::Path, crate::validation::Error),
+    {
+        // Generated part
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+        self.primitives.validate(root, || path().field("primitives"), report);
+        self.weights.validate(root, || path().field("weights"), report);
+
+        // Custom part
+        // spec: `weights` must have the same length as `targets`.
+        if let Some(weights) = self.weights.as_ref() {
+            for (index, primitive) in self.primitives.iter().enumerate() {
+                let targets = primitive.targets.as_ref().map_or(0, Vec::len);
+                if targets != weights.len() {
+                    report(&|| path().field("primitives").index(index).field("targets"), Error::Invalid);
+                }
+            }
+        }
+    }
+}
+
 /// Geometry to be rendered with the given material.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Primitive {
     /// Maps attribute semantic names to the `Accessor`s containing the
     /// corresponding attribute data.
-    pub attributes: HashMap<Checked<Semantic>, Index<accessor::Accessor>>,
+    ///
+    /// Attributes are kept in the order of `Semantic`, so serialization is
+    /// reproducible.
+    pub attributes: BTreeMap<Checked<Semantic>, Index<accessor::Accessor>>,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -113,7 +141,7 @@ pub struct Primitive {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 
     /// The index of the accessor that contains the indices.
@@ -139,6 +167,16 @@ fn is_primitive_mode_default(mode: &Checked<Mode>) -> bool {
     *mode == Checked::Valid(Mode::Triangles)
 }
 
+impl Primitive {
+    /// Returns the accessor for the given attribute semantic, if present.
+    ///
+    /// Attribute names that do not parse as a `Semantic` are only reachable
+    /// through the `attributes` map.
+    pub fn attribute(&self, semantic: Semantic) -> Option<Index<accessor::Accessor>> {
+        self.attributes.get(&Checked::Valid(semantic)).cloned()
+    }
+}
+
 impl Validate for Primitive {
     fn validate<P, R>(&self, root: &crate::Root, path: P, report: &mut R)
         where
@@ -155,10 +193,30 @@ impl Validate for Primitive {
         self.targets.validate(root, || path().field("targets"), report);
 
         // Custom part
+        // spec: all attribute accessors **must** have the same count.
+        if let Some(count) = root.primitive_vertex_count(self) {
+            for (semantic, index) in &self.attributes {
+                let semantic = match *semantic {
+                    Checked::Valid(ref semantic) => semantic,
+                    Checked::Invalid => continue,
+                };
+                match root.accessors.get(index.value()) {
+                    Some(accessor) if accessor.count != count => {
+                        report(&|| path().field("attributes").key(&semantic.to_string()), Error::Invalid);
+                    },
+                    _ => {},
+                }
+            }
+        }
+
         let position_path = &|| path().field("attributes").key("POSITION");
         if let Some(pos_accessor_index) = self.attributes.get(&Checked::Valid(Semantic::Positions)) {
             // spec: POSITION accessor **must** have `min` and `max` properties defined.
-            let pos_accessor = &root.accessors[pos_accessor_index.value()];
+            let pos_accessor = match root.accessors.get(pos_accessor_index.value()) {
+                Some(accessor) => accessor,
+                // Already reported as out of bounds above.
+                None => return,
+            };
 
             let min_path = &|| position_path().field("min");
             if let Some(ref min) = pos_accessor.min {
@@ -203,12 +261,8 @@ pub struct MorphTarget {
 }
 
 /// Vertex attribute semantic name.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Semantic {
-    /// Extra attribute name.
-    #[cfg(feature = "extras")]
-    Extras(String),
-
     /// XYZ vertex positions.
     Positions,
 
@@ -230,6 +284,10 @@ pub enum Semantic {
 
     /// Joint weights.
     Weights(u32),
+
+    /// Extra attribute name.
+    #[cfg(feature = "extras")]
+    Extras(String),
 }
 
 impl Default for Mode {
@@ -342,19 +400,31 @@ impl ser::Serialize for Semantic {
     }
 }
 
-impl ToString for Semantic {
-    fn to_string(&self) -> String {
+impl fmt::Display for Semantic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::Semantic::*;
         match *self {
-            Positions => "POSITION".into(),
-            Normals => "NORMAL".into(),
-            Tangents => "TANGENT".into(),
-            Colors(set) => format!("COLOR_{}", set),
-            TexCoords(set) => format!("TEXCOORD_{}", set),
-            Joints(set) => format!("JOINTS_{}", set),
-            Weights(set) => format!("WEIGHTS_{}", set),
+            Positions => write!(f, "POSITION"),
+            Normals => write!(f, "NORMAL"),
+            Tangents => write!(f, "TANGENT"),
+            Colors(set) => write!(f, "COLOR_{}", set),
+            TexCoords(set) => write!(f, "TEXCOORD_{}", set),
+            Joints(set) => write!(f, "JOINTS_{}", set),
+            Weights(set) => write!(f, "WEIGHTS_{}", set),
             #[cfg(feature = "extras")]
-            Extras(ref name) => format!("_{}", name),
+            Extras(ref name) => write!(f, "_{}", name),
+        }
+    }
+}
+
+impl str::FromStr for Semantic {
+    type Err = Error;
+
+    /// Parses an attribute semantic name such as `"TEXCOORD_0"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Semantic::checked(s) {
+            Checked::Valid(semantic) => Ok(semantic),
+            Checked::Invalid => Err(Error::Invalid),
         }
     }
 }
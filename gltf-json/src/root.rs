@@ -1,8 +1,8 @@
-use gltf_derive::Validate;
 use crate::buffer;
 use crate::extensions;
+use crate::mesh;
 use serde_derive::{Serialize, Deserialize};
-use std::{self, fmt, io, marker};
+use std::{self, cmp, fmt, hash, io, marker};
 use crate::texture;
 use crate::validation;
 
@@ -14,13 +14,16 @@ use crate::{Accessor, Animation, Asset, Buffer, Camera, Error, Extras, Image, Ma
 pub trait Get<T> {
     /// Retrieves a single value at the given index.
     fn get(&self, id: Index<T>) -> Option<&T>;
+
+    /// Returns the number of values that can be retrieved.
+    fn len(&self) -> usize;
 }
 
 /// Represents an offset into an array of type `T` owned by the root glTF object.
 pub struct Index<T>(u32, marker::PhantomData<*const T>);
 
 /// The root object of a glTF 2.0 asset.
-#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Root {
     /// An array of accessors.
     #[serde(default)]
@@ -55,7 +58,7 @@ pub struct Root {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 
     /// Names of glTF extensions used somewhere in this asset.
@@ -114,6 +117,161 @@ pub struct Root {
     pub textures: Vec<Texture>,
 }
 
+/// The `asset.version` of a document, read by `Root::asset_version`.
+#[derive(Deserialize)]
+struct VersionHeader {
+    asset: VersionAsset,
+}
+
+#[derive(Deserialize)]
+struct VersionAsset {
+    version: String,
+}
+
+impl Validate for Root {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, validation::Error)
+    {
+        // Generated part
+        self.accessors.validate(root, || path().field("accessors"), report);
+        self.animations.validate(root, || path().field("animations"), report);
+        self.asset.validate(root, || path().field("asset"), report);
+        self.buffers.validate(root, || path().field("buffers"), report);
+        self.buffer_views.validate(root, || path().field("bufferViews"), report);
+        self.scene.validate(root, || path().field("scene"), report);
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+        self.extensions_used.validate(root, || path().field("extensionsUsed"), report);
+        self.extensions_required.validate(root, || path().field("extensionsRequired"), report);
+        self.cameras.validate(root, || path().field("cameras"), report);
+        self.images.validate(root, || path().field("images"), report);
+        self.materials.validate(root, || path().field("materials"), report);
+        self.meshes.validate(root, || path().field("meshes"), report);
+        self.nodes.validate(root, || path().field("nodes"), report);
+        self.samplers.validate(root, || path().field("samplers"), report);
+        self.scenes.validate(root, || path().field("scenes"), report);
+        self.skins.validate(root, || path().field("skins"), report);
+        self.textures.validate(root, || path().field("textures"), report);
+
+        // Custom part
+        for (index, name) in self.extensions_required.iter().enumerate() {
+            // spec: every required extension must also be listed as used.
+            if !self.extensions_used.contains(name) {
+                report(&|| path().field("extensionsRequired").index(index), validation::Error::Invalid);
+            }
+        }
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            // spec: a node can not be its own child.
+            if let Some(children) = node.children.as_ref() {
+                for (child_index, child) in children.iter().enumerate() {
+                    if child.value() == index {
+                        let path = || path().field("nodes").index(index).field("children").index(child_index);
+                        report(&path, validation::Error::Invalid);
+                    }
+                }
+            }
+
+            // spec: a node with a matrix must not also have TRS properties.
+            let trs = node.translation.is_some() || node.rotation.is_some() || node.scale.is_some();
+            if node.matrix.is_some() && trs {
+                report(&|| path().field("nodes").index(index).field("matrix"), validation::Error::Invalid);
+            }
+
+            // spec: when node `weights` is defined, `mesh` must also be
+            // defined, and `weights` must have one entry per morph target of
+            // that mesh.
+            if let Some(weights) = node.weights.as_ref() {
+                let invalid = match node.mesh.map(|mesh| self.get(mesh)) {
+                    None => true,
+                    // An out of range mesh index is reported by the index check.
+                    Some(None) => false,
+                    // Primitives are not guaranteed to agree on their number
+                    // of targets, so check against every one of them.
+                    Some(Some(mesh)) => mesh.primitives.iter().any(|primitive| {
+                        primitive.targets.as_ref().map_or(0, Vec::len) != weights.len()
+                    }),
+                };
+                if invalid {
+                    report(&|| path().field("nodes").index(index).field("weights"), validation::Error::Invalid);
+                }
+            }
+        }
+
+        // spec: nodes form disjoint strict trees, so every node has at most
+        // one parent and the hierarchy contains no cycles.
+        let mut parents = vec![None; self.nodes.len()];
+        for (index, node) in self.nodes.iter().enumerate() {
+            for (child_index, child) in node.children.iter().flatten().enumerate() {
+                let child = child.value();
+                if child == index || child >= parents.len() {
+                    // Reported above.
+                    continue;
+                }
+                if parents[child].is_some() {
+                    let path = || path().field("nodes").index(index).field("children").index(child_index);
+                    report(&path, validation::Error::Invalid);
+                } else {
+                    parents[child] = Some((index, child_index));
+                }
+            }
+        }
+        // Follow the parent links from each node, marking nodes with the
+        // walk that first reached them; reaching a node marked by the
+        // current walk means a cycle.
+        let mut marks = vec![0; parents.len()];
+        for start in 0..parents.len() {
+            let mut current = start;
+            let mut cycle = false;
+            loop {
+                if marks[current] != 0 {
+                    cycle = marks[current] == start + 1;
+                    break;
+                }
+                marks[current] = start + 1;
+                match parents[current] {
+                    Some((parent, _)) => current = parent,
+                    None => break,
+                }
+            }
+            if cycle {
+                let (parent, child_index) = parents[current].unwrap();
+                let path = || path().field("nodes").index(parent).field("children").index(child_index);
+                report(&path, validation::Error::Invalid);
+            }
+        }
+
+        for (index, view) in self.buffer_views.iter().enumerate() {
+            // spec: a buffer view must fit within its buffer.
+            let buffer = match self.get(view.buffer) {
+                Some(buffer) => buffer,
+                None => continue,
+            };
+            let end = u64::from(view.byte_offset.unwrap_or(0)) + u64::from(view.byte_length);
+            if end > u64::from(buffer.byte_length) {
+                report(&|| path().field("bufferViews").index(index), validation::Error::Invalid);
+            }
+        }
+
+        for (index, accessor) in self.accessors.iter().enumerate() {
+            // spec: an accessor must fit within its buffer view.
+            let view = match self.get(accessor.buffer_view) {
+                Some(view) => view,
+                None => continue,
+            };
+            let size = match accessor.element_size() {
+                Some(size) if accessor.count > 0 => size as u64,
+                _ => continue,
+            };
+            let stride = view.byte_stride.map_or(size, u64::from);
+            let end = u64::from(accessor.byte_offset) + (u64::from(accessor.count) - 1) * stride + size;
+            if end > u64::from(view.byte_length) {
+                report(&|| path().field("accessors").index(index), validation::Error::Invalid);
+            }
+        }
+    }
+}
+
 impl Root {
     /// Returns a single item from the root object.
     pub fn get<T>(&self, index: Index<T>) -> Option<&T>
@@ -122,6 +280,350 @@ impl Root {
         (self as &dyn Get<T>).get(index)
     }
 
+    /// Returns the default scene.
+    ///
+    /// Returns `None` when no default scene is declared or the declared index
+    /// is out of range.
+    pub fn default_scene(&self) -> Option<&Scene> {
+        self.scene.and_then(|index| self.get(index))
+    }
+
+    /// Checks that every extension required by this asset is in the given
+    /// list of supported extensions.
+    ///
+    /// Returns the name of the first required extension that is not supported.
+    pub fn check_required_extensions(&self, supported: &[&str]) -> Result<(), &str> {
+        match self.extensions_required.iter().find(|name| !supported.contains(&name.as_str())) {
+            Some(name) => Err(name),
+            None => Ok(()),
+        }
+    }
+
+    /// Performs the usual validation checks plus the numeric constraints that
+    /// the glTF JSON schema specifies but deserialization does not enforce.
+    ///
+    /// The additional checks include:
+    ///
+    /// * accessor and sparse `count` must be at least 1,
+    /// * buffer and buffer view `byteLength` must be at least 1,
+    /// * buffer view `byteStride` must be within `[4, 252]`,
+    /// * material factors must be within `[0.0, 1.0]` and `alphaCutoff`
+    ///   must be non-negative,
+    /// * perspective `yfov` and `znear` must be positive, `zfar` must exceed
+    ///   `znear`, and `aspectRatio` must be positive.
+    pub fn validate_strict(&self) -> Vec<(Path, validation::Error)> {
+        use validation::Error::Invalid;
+
+        let mut errors = Vec::new();
+        self.validate(self, Path::new, &mut |path, error| errors.push((path(), error)));
+
+        // Written such that NaN is rejected.
+        fn in_unit_range(x: f32) -> bool {
+            (0.0..=1.0).contains(&x)
+        }
+
+        for (i, accessor) in self.accessors.iter().enumerate() {
+            let path = Path::new().field("accessors").index(i);
+            if accessor.count < 1 {
+                errors.push((path.field("count"), Invalid));
+            }
+            if let Some(sparse) = accessor.sparse.as_ref() {
+                if sparse.count < 1 {
+                    errors.push((path.field("sparse").field("count"), Invalid));
+                }
+            }
+        }
+        for (i, buffer) in self.buffers.iter().enumerate() {
+            if buffer.byte_length < 1 {
+                errors.push((Path::new().field("buffers").index(i).field("byteLength"), Invalid));
+            }
+        }
+        for (i, view) in self.buffer_views.iter().enumerate() {
+            let path = Path::new().field("bufferViews").index(i);
+            if view.byte_length < 1 {
+                errors.push((path.field("byteLength"), Invalid));
+            }
+            if let Some(stride) = view.byte_stride {
+                if !(4..=252).contains(&stride) {
+                    errors.push((path.field("byteStride"), Invalid));
+                }
+            }
+        }
+        for (i, material) in self.materials.iter().enumerate() {
+            let path = Path::new().field("materials").index(i);
+            let pbr = &material.pbr_metallic_roughness;
+            let pbr_path = path.field("pbrMetallicRoughness");
+            if !pbr.base_color_factor.0.iter().cloned().all(in_unit_range) {
+                errors.push((pbr_path.field("baseColorFactor"), Invalid));
+            }
+            if !in_unit_range(pbr.metallic_factor.0) {
+                errors.push((pbr_path.field("metallicFactor"), Invalid));
+            }
+            if !in_unit_range(pbr.roughness_factor.0) {
+                errors.push((pbr_path.field("roughnessFactor"), Invalid));
+            }
+            if !material.emissive_factor.0.iter().cloned().all(in_unit_range) {
+                errors.push((path.field("emissiveFactor"), Invalid));
+            }
+            if let Some(occlusion) = material.occlusion_texture.as_ref() {
+                if !in_unit_range(occlusion.strength.0) {
+                    errors.push((path.field("occlusionTexture").field("strength"), Invalid));
+                }
+            }
+            if material.alpha_cutoff.0 < 0.0 {
+                errors.push((path.field("alphaCutoff"), Invalid));
+            }
+        }
+        for (i, camera) in self.cameras.iter().enumerate() {
+            if let Some(perspective) = camera.perspective.as_ref() {
+                let path = Path::new().field("cameras").index(i).field("perspective");
+                // NaN fails every comparison, so it is rejected explicitly.
+                if perspective.yfov.is_nan() || perspective.yfov <= 0.0 {
+                    errors.push((path.field("yfov"), Invalid));
+                }
+                if perspective.znear.is_nan() || perspective.znear <= 0.0 {
+                    errors.push((path.field("znear"), Invalid));
+                }
+                if let Some(zfar) = perspective.zfar {
+                    if zfar.is_nan() || zfar <= perspective.znear {
+                        errors.push((path.field("zfar"), Invalid));
+                    }
+                }
+                if let Some(aspect_ratio) = perspective.aspect_ratio {
+                    if aspect_ratio.is_nan() || aspect_ratio <= 0.0 {
+                        errors.push((path.field("aspectRatio"), Invalid));
+                    }
+                }
+            }
+        }
+
+        errors
+    }
+
+    /// Returns the parent of every node, indexed by node.
+    ///
+    /// Root nodes and children that are out of range are skipped. Since the
+    /// node hierarchy must be a strict forest, a node that is the child of
+    /// more than one node is an error, and the first such node found is
+    /// returned as `Err`.
+    pub fn parent_map(&self) -> Result<Vec<Option<Index<Node>>>, Index<Node>> {
+        let mut parents = vec![None; self.nodes.len()];
+        for (index, node) in self.nodes.iter().enumerate() {
+            for child in node.children.iter().flatten() {
+                match parents.get_mut(child.value()) {
+                    Some(Some(_)) => return Err(*child),
+                    Some(parent) => *parent = Some(Index::new(index as u32)),
+                    None => {},
+                }
+            }
+        }
+        Ok(parents)
+    }
+
+    /// Returns every buffer view that refers to the given buffer, in order,
+    /// together with its index.
+    pub fn buffer_views_of(
+        &self,
+        buffer: Index<Buffer>,
+    ) -> impl Iterator<Item = (Index<buffer::View>, &buffer::View)> {
+        self.buffer_views
+            .iter()
+            .enumerate()
+            .filter(move |(_, view)| view.buffer == buffer)
+            .map(|(index, view)| (Index::new(index as u32), view))
+    }
+
+    /// Returns the number of vertices of a mesh primitive.
+    ///
+    /// This is the `count` of the `POSITION` accessor, or of any other
+    /// attribute accessor when there is no `POSITION` attribute. Returns
+    /// `None` when the primitive has no attributes or the accessor is out of
+    /// range. Validation checks that all attribute accessors agree.
+    pub fn primitive_vertex_count(&self, primitive: &mesh::Primitive) -> Option<u32> {
+        primitive.attribute(mesh::Semantic::Positions)
+            .or_else(|| primitive.attributes.values().next().cloned())
+            .and_then(|index| self.get(index))
+            .map(|accessor| accessor.count)
+    }
+
+    /// Removes a node from the node hierarchy and remaps every node index in
+    /// the document accordingly.
+    ///
+    /// When `relink_children` is `true` the children of the removed node take
+    /// its place in the children of its parents and in the root nodes of the
+    /// scenes that contain it. Otherwise the whole subtree rooted at the node
+    /// is removed. Note that the transform of the removed node is not applied
+    /// to relinked children.
+    ///
+    /// Skeletons referring to removed nodes are unset and animation channels
+    /// targeting removed nodes are deleted. Skin joints are never removed,
+    /// since that would shift the joint indices used by `JOINTS_n` vertex
+    /// attributes and `inverseBindMatrices`: if a node that would be removed
+    /// is a skin joint, that joint is returned as `Err` and the document is
+    /// left unchanged.
+    pub fn remove_node(&mut self, node: Index<Node>, relink_children: bool) -> Result<(), Index<Node>> {
+        let target = node.value();
+        if target >= self.nodes.len() {
+            return Ok(());
+        }
+
+        let mut removed = vec![false; self.nodes.len()];
+        let mut stack = vec![target];
+        while let Some(index) = stack.pop() {
+            if index >= removed.len() || removed[index] {
+                continue;
+            }
+            removed[index] = true;
+            if !relink_children {
+                if let Some(children) = self.nodes[index].children.as_ref() {
+                    stack.extend(children.iter().map(Index::value));
+                }
+            }
+        }
+        for skin in &self.skins {
+            if let Some(joint) = skin.joints.iter().find(|joint| removed.get(joint.value()) == Some(&true)) {
+                return Err(*joint);
+            }
+        }
+
+        let promoted: Vec<Index<Node>> = if relink_children {
+            self.nodes[target].children.clone().unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let relink = |list: &[Index<Node>]| -> Vec<Index<Node>> {
+            let mut result = Vec::with_capacity(list.len());
+            for index in list {
+                if index.value() == target {
+                    result.extend(promoted.iter().filter(|child| child.value() != target));
+                } else {
+                    result.push(*index);
+                }
+            }
+            result
+        };
+
+        let mut remap = Vec::with_capacity(self.nodes.len());
+        let mut next = 0;
+        for &is_removed in &removed {
+            if is_removed {
+                remap.push(None);
+            } else {
+                remap.push(Some(Index::new(next)));
+                next += 1;
+            }
+        }
+        let map = |index: &Index<Node>| remap.get(index.value()).cloned().unwrap_or(None);
+        let map_all = |list: &[Index<Node>]| -> Vec<Index<Node>> {
+            relink(list).iter().filter_map(&map).collect()
+        };
+
+        self.nodes = self.nodes
+            .drain(..)
+            .zip(&removed)
+            .filter(|&(_, &is_removed)| !is_removed)
+            .map(|(node, _)| node)
+            .collect();
+        for node in &mut self.nodes {
+            if let Some(children) = node.children.take() {
+                let children = map_all(&children);
+                if !children.is_empty() {
+                    node.children = Some(children);
+                }
+            }
+        }
+        for scene in &mut self.scenes {
+            scene.nodes = map_all(&scene.nodes);
+        }
+        for skin in &mut self.skins {
+            // Joints are not removed, see above.
+            for joint in &mut skin.joints {
+                if let Some(index) = map(joint) {
+                    *joint = index;
+                }
+            }
+            skin.skeleton = skin.skeleton.as_ref().and_then(&map);
+        }
+        for animation in &mut self.animations {
+            animation.channels = animation.channels
+                .drain(..)
+                .filter_map(|mut channel| {
+                    map(&channel.target.node).map(|node| {
+                        channel.target.node = node;
+                        channel
+                    })
+                })
+                .collect();
+        }
+        Ok(())
+    }
+
+    /// Makes objects with identical names share the storage of a single
+    /// `Arc<str>`.
+    ///
+    /// Some exporters give thousands of objects the same name, so interning
+    /// can save a significant amount of memory on large files.
+    #[cfg(feature = "intern_names")]
+    pub fn intern_names(&mut self) {
+        let mut names = std::collections::HashSet::<crate::Name>::new();
+        let mut intern = |name: &mut Option<crate::Name>| {
+            if let Some(name) = name.as_mut() {
+                match names.get(name) {
+                    Some(shared) => *name = shared.clone(),
+                    None => {
+                        names.insert(name.clone());
+                    },
+                }
+            }
+        };
+        for accessor in &mut self.accessors {
+            intern(&mut accessor.name);
+        }
+        for animation in &mut self.animations {
+            intern(&mut animation.name);
+        }
+        for buffer in &mut self.buffers {
+            intern(&mut buffer.name);
+        }
+        for view in &mut self.buffer_views {
+            intern(&mut view.name);
+        }
+        for camera in &mut self.cameras {
+            intern(&mut camera.name);
+        }
+        for image in &mut self.images {
+            intern(&mut image.name);
+        }
+        for material in &mut self.materials {
+            intern(&mut material.name);
+        }
+        for mesh in &mut self.meshes {
+            intern(&mut mesh.name);
+        }
+        for node in &mut self.nodes {
+            intern(&mut node.name);
+        }
+        for sampler in &mut self.samplers {
+            intern(&mut sampler.name);
+        }
+        for scene in &mut self.scenes {
+            intern(&mut scene.name);
+        }
+        for skin in &mut self.skins {
+            intern(&mut skin.name);
+        }
+        for texture in &mut self.textures {
+            intern(&mut texture.name);
+        }
+        #[cfg(feature = "KHR_lights_punctual")]
+        {
+            let extensions = self.extensions.as_mut().and_then(|x| x.khr_lights_punctual.as_mut());
+            for light in extensions.into_iter().flat_map(|x| &mut x.lights) {
+                intern(&mut light.name);
+            }
+        }
+    }
+
     /// Deserialize from a JSON string slice.
     pub fn from_str(str_: &str) -> Result<Self, Error> {
         serde_json::from_str(str_)
@@ -139,6 +641,78 @@ impl Root {
         serde_json::from_reader(reader)
     }
 
+    /// Deserialize from a pre-parsed generic JSON value.
+    pub fn from_value(value: Value) -> Result<Self, Error> {
+        serde_json::from_value(value)
+    }
+
+    /// Scans a JSON byte slice for keys that are declared more than once in
+    /// the root object.
+    ///
+    /// `serde_json` silently keeps the last value of a duplicated key, which
+    /// can mask buggy or malicious files. Each duplicated key is returned
+    /// once, in order of its second occurrence.
+    ///
+    /// This is not run when loading glTF, since it needs the whole document
+    /// in memory and would double the parsing cost of every load; callers
+    /// that want the warning must scan the JSON themselves first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use gltf_json::Root;
+    /// let json = br#"{ "asset": { "version": "2.0" }, "meshes": [], "meshes": [] }"#;
+    /// for key in Root::duplicate_keys(json).unwrap() {
+    ///     eprintln!("warning: duplicate key `{}`", key);
+    /// }
+    /// # assert_eq!(Root::duplicate_keys(json).unwrap(), vec!["meshes".to_string()]);
+    /// ```
+    pub fn duplicate_keys(slice: &[u8]) -> Result<Vec<String>, Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Vec<String>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a JSON object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                where A: serde::de::MapAccess<'de>
+            {
+                let mut keys = std::collections::HashSet::new();
+                let mut duplicates = Vec::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                    if !keys.insert(key.clone()) && !duplicates.contains(&key) {
+                        duplicates.push(key);
+                    }
+                }
+                Ok(duplicates)
+            }
+        }
+        let mut deserializer = serde_json::Deserializer::from_slice(slice);
+        let duplicates = serde::Deserializer::deserialize_map(&mut deserializer, Visitor)?;
+        deserializer.end()?;
+        Ok(duplicates)
+    }
+
+    /// Reads only `asset.version` from a JSON byte slice.
+    ///
+    /// This identifies documents of other glTF major versions, whose layout
+    /// differs from glTF 2.0, without deserializing the rest of the document.
+    pub fn asset_version(slice: &[u8]) -> Result<String, Error> {
+        serde_json::from_slice::<VersionHeader>(slice).map(|header| header.asset.version)
+    }
+
+    /// Reads only `asset.version` from a stream of JSON.
+    ///
+    /// The rest of the document is parsed but not stored.
+    pub fn asset_version_from_reader<R>(reader: R) -> Result<String, Error>
+        where R: io::Read
+    {
+        serde_json::from_reader::<_, VersionHeader>(reader).map(|header| header.asset.version)
+    }
+
     /// Serialize as a `String` of JSON.
     pub fn to_string(&self) -> Result<String, Error> {
         serde_json::to_string(self)
@@ -189,6 +763,22 @@ impl<T> Index<T> {
     pub fn value(&self) -> usize {
         self.0 as usize
     }
+
+    /// Returns the item this index refers to, or `None` if it is out of
+    /// range.
+    ///
+    /// This is equivalent to `root.get(index)`.
+    pub fn get(self, root: &Root) -> Option<&T>
+        where Root: Get<T>
+    {
+        root.get(self)
+    }
+}
+
+impl<T> From<u32> for Index<T> {
+    fn from(value: u32) -> Self {
+        Index::new(value)
+    }
 }
 
 impl<T> serde::Serialize for Index<T> {
@@ -229,6 +819,32 @@ impl<T> Clone for Index<T> {
 
 impl<T> Copy for Index<T> {}
 
+impl<T> PartialEq for Index<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for Index<T> {}
+
+impl<T> PartialOrd for Index<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Index<T> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T> hash::Hash for Index<T> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
 unsafe impl<T> Send for Index<T> {}
 unsafe impl<T> Sync for Index<T> {}
 
@@ -251,17 +867,51 @@ impl<T: Validate> Validate for Index<T>
         where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, validation::Error)
     {
         if root.get(*self).is_none() {
-            report(&path, validation::Error::IndexOutOfBounds);
+            report(&path, validation::Error::IndexOutOfBounds { len: root.len() });
+        }
+    }
+}
+
+macro_rules! impl_by_name {
+    ($($name:ident, $ty:ty, $field:ident;)*) => {
+        #[cfg(feature = "names")]
+        impl Root {
+            $(
+                /// Returns the index of the first object of this type with
+                /// the given name, together with the object itself.
+                ///
+                /// Names are not required to be unique, so other objects may
+                /// share the name.
+                pub fn $name(&self, name: &str) -> Option<(Index<$ty>, &$ty)> {
+                    self.$field
+                        .iter()
+                        .position(|item| item.name.as_ref().map(|name| &**name) == Some(name))
+                        .map(|index| (Index::new(index as u32), &self.$field[index]))
+                }
+            )*
         }
     }
 }
 
+impl_by_name! {
+    accessor_by_name, Accessor, accessors;
+    animation_by_name, Animation, animations;
+    material_by_name, Material, materials;
+    mesh_by_name, Mesh, meshes;
+    node_by_name, Node, nodes;
+    scene_by_name, Scene, scenes;
+}
+
 macro_rules! impl_get {
     ($ty:ty, $field:ident) => {
         impl<'a> Get<$ty> for Root {
             fn get(&self, index: Index<$ty>) -> Option<&$ty> {
                 self.$field.get(index.value())
             }
+
+            fn len(&self) -> usize {
+                self.$field.len()
+            }
         }
     }
 }
@@ -152,14 +152,14 @@ pub struct Sampler {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Name>,
 
     /// `s` wrapping mode.
-    #[serde(default, rename = "wrapS")]
+    #[serde(default, rename = "wrapS", skip_serializing_if = "is_wrapping_mode_default")]
     pub wrap_s: Checked<WrappingMode>,
 
     /// `t` wrapping mode.
-    #[serde(default, rename = "wrapT")]
+    #[serde(default, rename = "wrapT", skip_serializing_if = "is_wrapping_mode_default")]
     pub wrap_t: Checked<WrappingMode>,
 
     /// Extension specific data.
@@ -168,7 +168,7 @@ pub struct Sampler {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
@@ -178,14 +178,21 @@ pub struct Texture {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Name>,
 
     /// The index of the sampler used by this texture.
+    ///
+    /// When undefined, a sampler with repeat wrapping and auto filtering
+    /// should be used.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sampler: Option<Index<Sampler>>,
 
     /// The index of the image used by this texture.
-    pub source: Index<image::Image>,
+    ///
+    /// When undefined, an extension or other mechanism should supply an
+    /// alternate texture source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Index<image::Image>>,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -193,7 +200,7 @@ pub struct Texture {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
@@ -204,7 +211,7 @@ pub struct Info {
     pub index: Index<Texture>,
 
     /// The set index of the texture's `TEXCOORD` attribute.
-    #[serde(default, rename = "texCoord")]
+    #[serde(default, rename = "texCoord", skip_serializing_if = "is_tex_coord_default")]
     pub tex_coord: u32,
 
     /// Extension specific data.
@@ -213,10 +220,20 @@ pub struct Info {
 
     /// Optional application specific data.
     #[serde(default)]
-    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[serde(skip_serializing_if = "crate::extras::is_empty")]
     pub extras: Extras,
 }
 
+// Help serde avoid serializing this glTF 2.0 default value.
+pub(crate) fn is_tex_coord_default(tex_coord: &u32) -> bool {
+    *tex_coord == 0
+}
+
+// Help serde avoid serializing this glTF 2.0 default value.
+fn is_wrapping_mode_default(mode: &Checked<WrappingMode>) -> bool {
+    *mode == Checked::Valid(WrappingMode::Repeat)
+}
+
 impl<'de> de::Deserialize<'de> for Checked<MagFilter> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: de::Deserializer<'de>
@@ -1,5 +1,5 @@
 use serde::{ser, Serialize, Serializer};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 
 use crate::{Path, Root};
@@ -20,17 +20,23 @@ pub trait Validate {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Error {
     /// An index was found to be out of bounds.
-    IndexOutOfBounds,
+    IndexOutOfBounds {
+        /// The number of values the index can refer to.
+        len: usize,
+    },
 
     /// An invalid value was identified.
     Invalid,
 
     /// Some required data has been omitted.
     Missing,
+
+    /// A version string is not of the form `major.minor`.
+    InvalidVersion,
 }
 
 /// Specifies a type that has been pre-validated during deserialization or otherwise.
-#[derive(Debug, Eq, Hash, PartialEq)]
+#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Checked<T> {
     /// The item is valid.
     Valid(T),
@@ -111,6 +117,17 @@ impl<K: Eq + Hash + ToString + Validate, V: Validate> Validate for HashMap<K, V>
     }
 }
 
+impl<K: Ord + ToString + Validate, V: Validate> Validate for BTreeMap<K, V> {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        for (key, value) in self.iter() {
+            key.validate(root, || path().key(&key.to_string()), report);
+            value.validate(root, || path().key(&key.to_string()), report);
+        }
+    }
+}
+
 impl<T: Validate> Validate for Option<T> {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
         where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
@@ -142,9 +159,10 @@ impl Validate for std::boxed::Box<serde_json::value::RawValue> {
 impl std::error::Error for Error {
     fn description(&self) -> &str {
         match *self {
-            Error::IndexOutOfBounds => "Index out of bounds",
+            Error::IndexOutOfBounds { .. } => "Index out of bounds",
             Error::Invalid => "Invalid value",
             Error::Missing => "Missing data",
+            Error::InvalidVersion => "Invalid version string",
         }
     }
 }
@@ -152,6 +170,9 @@ impl std::error::Error for Error {
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         use std::error::Error;
+        if let Self::IndexOutOfBounds { len } = *self {
+            return write!(f, "Index out of bounds (have {})", len);
+        }
         write!(f, "{}", self.description())
     }
 }
@@ -166,4 +187,6 @@ impl Validate for [f32; 4] {}
 impl Validate for [f32; 16] {}
 impl Validate for () {}
 impl Validate for String {}
+#[cfg(feature = "intern_names")]
+impl Validate for std::sync::Arc<str> {}
 impl Validate for serde_json::Value {}
@@ -0,0 +1,39 @@
+use gltf_json::accessor::{ComponentType, Type};
+
+#[test]
+fn test_accessor_sizes() {
+    let sizes = [
+        ComponentType::I8,
+        ComponentType::U8,
+        ComponentType::I16,
+        ComponentType::U16,
+        ComponentType::U32,
+        ComponentType::F32,
+    ].iter().map(ComponentType::size).collect::<Vec<_>>();
+    assert_eq!(sizes, [1, 1, 2, 2, 4, 4]);
+
+    let multiplicities = [
+        Type::Scalar,
+        Type::Vec2,
+        Type::Vec3,
+        Type::Vec4,
+        Type::Mat2,
+        Type::Mat3,
+        Type::Mat4,
+    ].iter().map(Type::multiplicity).collect::<Vec<_>>();
+    assert_eq!(multiplicities, [1, 2, 3, 4, 4, 9, 16]);
+}
+
+#[test]
+fn test_accessor_element_size() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "accessors": [
+            { "bufferView": 0, "componentType": 5123, "count": 1, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "MAT4" },
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC5" }
+        ]
+    }"#).unwrap();
+    let sizes = json.accessors.iter().map(|x| x.element_size()).collect::<Vec<_>>();
+    assert_eq!(sizes, [Some(6), Some(64), None]);
+}
@@ -0,0 +1,46 @@
+use gltf_json::animation::Interpolation;
+use gltf_json::validation::Checked;
+
+#[test]
+fn test_animation_sampler() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "samplers": [ {} ],
+        "animations": [
+            {
+                "channels": [
+                    { "sampler": 1, "target": { "node": 0, "path": "rotation" } }
+                ],
+                "samplers": [
+                    { "input": 0, "output": 1 },
+                    { "input": 0, "output": 2, "interpolation": "STEP" }
+                ]
+            }
+        ]
+    }"#).unwrap();
+    let animation = &json.animations[0];
+    let sampler = animation.sampler(animation.channels[0].sampler).unwrap();
+    assert_eq!(sampler.output.value(), 2);
+    assert_eq!(sampler.interpolation, Checked::Valid(Interpolation::Step));
+}
+
+#[test]
+fn test_animation_weights_path() {
+    use gltf_json::animation::Property;
+
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "animations": [
+            {
+                "channels": [
+                    { "sampler": 0, "target": { "node": 0, "path": "weights" } }
+                ],
+                "samplers": [ { "input": 0, "output": 1 } ]
+            }
+        ]
+    }"#).unwrap();
+    let target = &json.animations[0].channels[0].target;
+    assert_eq!(target.path, Checked::Valid(Property::MorphTargetWeights));
+    let string = gltf_json::serialize::to_string(target).unwrap();
+    assert_eq!(string, r#"{"node":0,"path":"weights"}"#);
+}
@@ -0,0 +1,25 @@
+#[test]
+fn test_perspective_optional_properties() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "cameras": [
+            { "type": "perspective", "perspective": { "yfov": 0.75, "znear": 0.1 } },
+            { "type": "perspective", "perspective": { "aspectRatio": 1.5, "yfov": 0.75, "zfar": 100.0, "znear": 0.1 } }
+        ]
+    }"#).unwrap();
+
+    // Omitted properties are distinct from any explicit value.
+    let perspective = json.cameras[0].perspective.as_ref().unwrap();
+    assert_eq!(perspective.aspect_ratio, None);
+    assert_eq!(perspective.zfar, None);
+    let perspective = json.cameras[1].perspective.as_ref().unwrap();
+    assert_eq!(perspective.aspect_ratio, Some(1.5));
+    assert_eq!(perspective.zfar, Some(100.0));
+
+    let value = json.to_value().unwrap();
+    let perspective = value["cameras"][0]["perspective"].as_object().unwrap();
+    assert!(!perspective.contains_key("aspectRatio"));
+    assert!(!perspective.contains_key("zfar"));
+    assert_eq!(value["cameras"][1]["perspective"]["aspectRatio"], 1.5);
+    assert_eq!(value["cameras"][1]["perspective"]["zfar"], 100.0);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "extras")]
+
+use serde_derive::Deserialize;
+
+use gltf_json::extras::extras_as;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Metadata {
+    author: String,
+    revision: u32,
+}
+
+#[test]
+fn test_extras_as() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "extras": { "author": "Ada", "revision": 3 } },
+            {},
+            { "extras": { "author": "Ada", "revision": "three" } }
+        ]
+    }"#).unwrap();
+
+    let metadata = extras_as::<Metadata>(&json.nodes[0].extras).unwrap();
+    assert_eq!(metadata, Some(Metadata { author: "Ada".to_string(), revision: 3 }));
+    assert!(extras_as::<Metadata>(&json.nodes[1].extras).unwrap().is_none());
+    assert!(extras_as::<Metadata>(&json.nodes[2].extras).is_err());
+}
+
+#[test]
+fn test_extras_roundtrip() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "animations": [
+            {
+                "channels": [
+                    {
+                        "sampler": 0,
+                        "target": { "node": 0, "path": "scale", "extras": { "b": 2 } },
+                        "extras": { "a": 1 }
+                    }
+                ],
+                "samplers": [ { "input": 0, "output": 1, "extras": { "c": 3 } } ]
+            }
+        ],
+        "materials": [
+            {
+                "normalTexture": { "index": 0, "extras": { "d": 4 } },
+                "occlusionTexture": { "index": 0, "extras": { "e": 5 } },
+                "emissiveTexture": { "index": 0, "extras": { "f": 6 } }
+            }
+        ],
+        "meshes": [
+            { "primitives": [ { "attributes": { "POSITION": 0 }, "extras": { "g": 7 } } ] }
+        ]
+    }"#).unwrap();
+    let value = json.to_value().unwrap();
+    let animation = &value["animations"][0];
+    assert_eq!(animation["channels"][0]["extras"]["a"], 1);
+    assert_eq!(animation["channels"][0]["target"]["extras"]["b"], 2);
+    assert_eq!(animation["samplers"][0]["extras"]["c"], 3);
+    let material = &value["materials"][0];
+    assert_eq!(material["normalTexture"]["extras"]["d"], 4);
+    assert_eq!(material["occlusionTexture"]["extras"]["e"], 5);
+    assert_eq!(material["emissiveTexture"]["extras"]["f"], 6);
+    assert_eq!(value["meshes"][0]["primitives"][0]["extras"]["g"], 7);
+
+    let reimported = gltf_json::Root::from_value(value.clone()).unwrap();
+    assert_eq!(reimported.to_value().unwrap(), value);
+}
@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+
+use gltf_json::{Index, Mesh};
+
+#[test]
+fn test_index_hash_set() {
+    let mut set = HashSet::new();
+    assert!(set.insert(Index::<Mesh>::new(0)));
+    assert!(set.insert(Index::<Mesh>::new(3)));
+    assert!(!set.insert(Index::<Mesh>::new(0)));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Index::new(3)));
+    assert_eq!(Index::<Mesh>::new(1), Index::new(1));
+    assert_ne!(Index::<Mesh>::new(1), Index::new(2));
+}
+
+#[test]
+fn test_index_construct() {
+    use gltf_json::Buffer;
+
+    let index = Index::<Buffer>::new(7);
+    assert_eq!(index.value(), 7);
+    assert_eq!(Index::<Buffer>::from(7), index);
+    assert_eq!(gltf_json::serialize::to_string(&index).unwrap(), "7");
+}
+
+#[test]
+fn test_index_ord() {
+    use std::collections::BTreeSet;
+
+    let mut indices = vec![Index::<Mesh>::new(4), Index::new(0), Index::new(2), Index::new(0)];
+    indices.sort();
+    indices.dedup();
+    assert_eq!(indices, [Index::new(0), Index::new(2), Index::new(4)]);
+    assert!(Index::<Mesh>::new(1) < Index::new(2));
+
+    // `Mesh` itself is not ordered, so this only compiles if the bound is on `u32`.
+    let set = vec![Index::<Mesh>::new(3), Index::new(1)].into_iter().collect::<BTreeSet<_>>();
+    assert_eq!(set.into_iter().next(), Some(Index::new(1)));
+}
+
+#[test]
+fn test_root_get() {
+    use gltf_json::{buffer, texture, Accessor, Animation, Camera, Image, Material, Node, Root, Scene, Skin, Texture};
+
+    let root = Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 4 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 4 } ],
+        "meshes": [ { "primitives": [] } ],
+        "nodes": [ {}, { "mesh": 0 } ]
+    }"#).unwrap();
+
+    // Lookups never panic; out of range indices give `None`.
+    assert_eq!(root.get(Index::<Node>::new(1)).and_then(|node| node.mesh), Some(Index::new(0)));
+    assert!(root.get(Index::<Node>::new(2)).is_none());
+    assert!(root.get(Index::<Mesh>::new(0)).is_some());
+    assert!(root.get(Index::<Mesh>::new(1)).is_none());
+    assert_eq!(root.get(Index::<buffer::View>::new(0)).map(|view| view.byte_length), Some(4));
+    assert_eq!(root.get(Index::<gltf_json::Buffer>::new(0)).map(|buffer| buffer.byte_length), Some(4));
+    assert!(root.get(Index::<Accessor>::new(0)).is_none());
+    assert!(root.get(Index::<Animation>::new(0)).is_none());
+    assert!(root.get(Index::<Camera>::new(0)).is_none());
+    assert!(root.get(Index::<Image>::new(0)).is_none());
+    assert!(root.get(Index::<Material>::new(0)).is_none());
+    assert!(root.get(Index::<texture::Sampler>::new(0)).is_none());
+    assert!(root.get(Index::<Scene>::new(0)).is_none());
+    assert!(root.get(Index::<Skin>::new(0)).is_none());
+    assert!(root.get(Index::<Texture>::new(0)).is_none());
+}
+
+#[test]
+fn test_index_get() {
+    use gltf_json::{Node, Root};
+
+    let root = Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "meshes": [ { "primitives": [] } ],
+        "nodes": [ { "mesh": 0 } ]
+    }"#).unwrap();
+    let mesh = Index::<Node>::new(0).get(&root).and_then(|node| node.mesh).unwrap();
+    assert!(mesh.get(&root).is_some());
+    assert!(Index::<Mesh>::new(1).get(&root).is_none());
+    assert!(Index::<Node>::new(1).get(&root).is_none());
+}
+
+#[test]
+fn test_buffer_views_of() {
+    use gltf_json::Buffer;
+
+    let root = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 8 }, { "byteLength": 8 } ],
+        "bufferViews": [
+            { "buffer": 1, "byteLength": 4 },
+            { "buffer": 0, "byteLength": 8 },
+            { "buffer": 1, "byteOffset": 4, "byteLength": 4 }
+        ]
+    }"#).unwrap();
+    let views = root.buffer_views_of(Index::<Buffer>::new(1))
+        .map(|(index, view)| (index.value(), view.byte_offset))
+        .collect::<Vec<_>>();
+    assert_eq!(views, [(0, None), (2, Some(4))]);
+    assert_eq!(root.buffer_views_of(Index::new(0)).count(), 1);
+    assert_eq!(root.buffer_views_of(Index::new(2)).count(), 0);
+}
+
+#[test]
+fn test_default_scene() {
+    let mut root = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [ {} ],
+        "scenes": [ { "nodes": [] }, { "nodes": [ 0 ] } ]
+    }"#).unwrap();
+    assert!(root.scene.is_none());
+    assert!(root.default_scene().is_none());
+
+    root.scene = Some(Index::new(1));
+    assert_eq!(root.default_scene().map(|scene| scene.nodes.len()), Some(1));
+
+    root.scene = Some(Index::new(2));
+    assert!(root.default_scene().is_none());
+}
+
+#[test]
+fn test_check_required_extensions() {
+    let root = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_materials_unlit", "KHR_draco_mesh_compression", "EXT_meshopt_compression" ],
+        "extensionsRequired": [ "KHR_draco_mesh_compression", "EXT_meshopt_compression" ]
+    }"#).unwrap();
+    assert_eq!(
+        root.check_required_extensions(&["KHR_draco_mesh_compression", "EXT_meshopt_compression"]),
+        Ok(()),
+    );
+    assert_eq!(
+        root.check_required_extensions(&["KHR_draco_mesh_compression"]),
+        Err("EXT_meshopt_compression"),
+    );
+    assert_eq!(root.check_required_extensions(&[]), Err("KHR_draco_mesh_compression"));
+    assert_eq!(gltf_json::Root::default().check_required_extensions(&[]), Ok(()));
+}
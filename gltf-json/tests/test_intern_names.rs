@@ -0,0 +1,31 @@
+#![cfg(feature = "intern_names")]
+
+use std::sync::Arc;
+
+use gltf_json::Root;
+
+#[test]
+fn test_intern_names() {
+    let mut root = Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "name": "data", "byteLength": 8 } ],
+        "bufferViews": [
+            { "name": "view", "buffer": 0, "byteLength": 4 },
+            { "name": "view", "buffer": 0, "byteOffset": 4, "byteLength": 4 }
+        ],
+        "accessors": [
+            { "name": "view", "bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR" },
+            { "name": "other", "bufferView": 1, "componentType": 5126, "count": 1, "type": "SCALAR" }
+        ]
+    }"#).unwrap();
+    let name = |name: &Option<gltf_json::Name>| name.clone().unwrap();
+    assert!(!Arc::ptr_eq(&name(&root.buffer_views[0].name), &name(&root.buffer_views[1].name)));
+
+    root.intern_names();
+    let view = name(&root.buffer_views[0].name);
+    assert!(Arc::ptr_eq(&view, &name(&root.buffer_views[1].name)));
+    assert!(Arc::ptr_eq(&view, &name(&root.accessors[0].name)));
+    assert!(!Arc::ptr_eq(&view, &name(&root.accessors[1].name)));
+    assert_eq!(&*name(&root.accessors[1].name), "other");
+    assert_eq!(&*name(&root.buffers[0].name), "data");
+}
@@ -0,0 +1,99 @@
+use gltf_json::material::AlphaMode;
+use gltf_json::validation::Checked;
+
+#[test]
+fn test_material_alpha() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [
+            {},
+            { "alphaMode": "MASK", "alphaCutoff": 0.25 }
+        ]
+    }"#).unwrap();
+
+    let default = &json.materials[0];
+    assert_eq!(default.alpha_mode, Checked::Valid(AlphaMode::Opaque));
+    assert_eq!(default.alpha_cutoff.0, 0.5);
+
+    let masked = &json.materials[1];
+    assert_eq!(masked.alpha_mode, Checked::Valid(AlphaMode::Mask));
+    assert_eq!(masked.alpha_cutoff.0, 0.25);
+
+    let value = json.to_value().unwrap();
+    assert_eq!(value["materials"][1]["alphaMode"], "MASK");
+    assert_eq!(value["materials"][1]["alphaCutoff"], 0.25);
+
+    let roundtrip = gltf_json::Root::from_value(value).unwrap();
+    assert_eq!(roundtrip.materials[1].alpha_mode, Checked::Valid(AlphaMode::Mask));
+    assert_eq!(roundtrip.materials[1].alpha_cutoff.0, 0.25);
+}
+
+#[test]
+fn test_material_double_sided() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [ {}, { "doubleSided": true } ]
+    }"#).unwrap();
+    assert!(!json.materials[0].double_sided);
+    assert!(json.materials[1].double_sided);
+
+    let value = json.to_value().unwrap();
+    assert_eq!(value["materials"][1]["doubleSided"], true);
+    let roundtrip = gltf_json::Root::from_value(value).unwrap();
+    assert!(roundtrip.materials[1].double_sided);
+}
+
+#[test]
+fn test_material_optional_textures() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [ { "pbrMetallicRoughness": { "metallicFactor": 0.0 } } ]
+    }"#).unwrap();
+    let material = &json.materials[0];
+    assert!(material.normal_texture.is_none());
+    assert!(material.occlusion_texture.is_none());
+    assert!(material.emissive_texture.is_none());
+    assert_eq!(material.emissive_factor.0, [0.0, 0.0, 0.0]);
+    assert_eq!(material.pbr_metallic_roughness.metallic_factor.0, 0.0);
+}
+
+#[test]
+fn test_material_blend_roundtrip() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [ { "alphaMode": "BLEND", "doubleSided": true } ]
+    }"#).unwrap();
+    let material = &json.materials[0];
+    assert_eq!(material.alpha_mode, Checked::Valid(AlphaMode::Blend));
+    assert_eq!(material.alpha_cutoff.0, 0.5);
+    assert!(material.double_sided);
+
+    let roundtrip = gltf_json::Root::from_str(&json.to_string().unwrap()).unwrap();
+    let material = &roundtrip.materials[0];
+    assert_eq!(material.alpha_mode, Checked::Valid(AlphaMode::Blend));
+    assert_eq!(material.alpha_cutoff.0, 0.5);
+    assert!(material.double_sided);
+}
+
+#[test]
+fn test_material_base_color_only() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [ { "pbrMetallicRoughness": { "baseColorFactor": [ 1.0, 0.0, 0.0, 1.0 ] } } ]
+    }"#).unwrap();
+    let pbr = &json.materials[0].pbr_metallic_roughness;
+    assert_eq!(pbr.base_color_factor.0, [1.0, 0.0, 0.0, 1.0]);
+    assert!(pbr.base_color_texture.is_none());
+    assert!(pbr.metallic_roughness_texture.is_none());
+
+    // Absent textures are not written.
+    let value = json.to_value().unwrap();
+    let material = value["materials"][0].as_object().unwrap();
+    let pbr = material["pbrMetallicRoughness"].as_object().unwrap();
+    for key in &["normalTexture", "occlusionTexture", "emissiveTexture"] {
+        assert!(!material.contains_key(*key));
+    }
+    for key in &["baseColorTexture", "metallicRoughnessTexture"] {
+        assert!(!pbr.contains_key(*key));
+    }
+}
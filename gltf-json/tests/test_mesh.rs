@@ -0,0 +1,119 @@
+use gltf_json::mesh::Semantic;
+use gltf_json::validation::Checked;
+
+#[test]
+fn test_primitive_attribute() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "meshes": [
+            {
+                "primitives": [
+                    {
+                        "attributes": {
+                            "POSITION": 0,
+                            "NORMAL": 1,
+                            "TEXCOORD_1": 2,
+                            "JOINTS_0": 3,
+                            "WEIGHTS_0": 4,
+                            "COLOR_0": 5,
+                            "CUSTOM": 6
+                        }
+                    }
+                ]
+            }
+        ]
+    }"#).unwrap();
+    let primitive = &json.meshes[0].primitives[0];
+    let attribute = |semantic| primitive.attribute(semantic).map(|index| index.value());
+    assert_eq!(attribute(Semantic::Positions), Some(0));
+    assert_eq!(attribute(Semantic::Normals), Some(1));
+    assert_eq!(attribute(Semantic::Tangents), None);
+    assert_eq!(attribute(Semantic::TexCoords(0)), None);
+    assert_eq!(attribute(Semantic::TexCoords(1)), Some(2));
+    assert_eq!(attribute(Semantic::Joints(0)), Some(3));
+    assert_eq!(attribute(Semantic::Weights(0)), Some(4));
+    assert_eq!(attribute(Semantic::Colors(0)), Some(5));
+
+    // Unrecognized attributes are still present in the raw map.
+    assert_eq!(primitive.attributes.len(), 7);
+    assert!(primitive.attributes.contains_key(&Checked::Invalid));
+}
+
+#[test]
+fn test_primitive_vertex_count() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2" }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0, "TEXCOORD_0": 1 } },
+                    { "attributes": { "TEXCOORD_0": 1 } },
+                    { "attributes": {} },
+                    { "attributes": { "POSITION": 5 } }
+                ]
+            }
+        ]
+    }"#).unwrap();
+    let counts = json.meshes[0].primitives
+        .iter()
+        .map(|primitive| json.primitive_vertex_count(primitive))
+        .collect::<Vec<_>>();
+    assert_eq!(counts, [Some(4), Some(3), None, None]);
+}
+
+#[test]
+fn test_semantic_from_str() {
+    let names = ["POSITION", "NORMAL", "TANGENT", "COLOR_0", "TEXCOORD_1", "JOINTS_2", "WEIGHTS_3"];
+    let semantics = names.iter().map(|name| name.parse().unwrap()).collect::<Vec<Semantic>>();
+    assert_eq!(semantics, [
+        Semantic::Positions,
+        Semantic::Normals,
+        Semantic::Tangents,
+        Semantic::Colors(0),
+        Semantic::TexCoords(1),
+        Semantic::Joints(2),
+        Semantic::Weights(3),
+    ]);
+    for (semantic, name) in semantics.iter().zip(&names) {
+        assert_eq!(semantic.to_string(), *name);
+    }
+
+    for name in &["position", "TEXCOORD", "TEXCOORD_", "COLOR_x", "CUSTOM"] {
+        assert!(name.parse::<Semantic>().is_err());
+    }
+}
+
+#[test]
+fn test_primitive_attribute_order() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "meshes": [
+            {
+                "primitives": [
+                    {
+                        "attributes": {
+                            "WEIGHTS_0": 5,
+                            "TEXCOORD_1": 3,
+                            "JOINTS_0": 4,
+                            "TEXCOORD_0": 2,
+                            "NORMAL": 1,
+                            "POSITION": 0
+                        }
+                    }
+                ]
+            }
+        ]
+    }"#).unwrap();
+    let string = gltf_json::serialize::to_string(&json.meshes[0].primitives[0]).unwrap();
+    assert_eq!(
+        string,
+        r#"{"attributes":{"POSITION":0,"NORMAL":1,"TEXCOORD_0":2,"TEXCOORD_1":3,"JOINTS_0":4,"WEIGHTS_0":5}}"#,
+    );
+
+    let reimported = gltf_json::Root::from_str(&json.to_string().unwrap()).unwrap();
+    assert_eq!(reimported.to_string().unwrap(), json.to_string().unwrap());
+}
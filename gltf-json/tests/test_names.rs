@@ -0,0 +1,31 @@
+#![cfg(feature = "names")]
+
+use gltf_json::Root;
+
+#[test]
+fn test_by_name() {
+    let root = Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "meshes": [
+            { "primitives": [] },
+            { "name": "Cube", "primitives": [] },
+            { "name": "Cube", "primitives": [] }
+        ],
+        "nodes": [ { "name": "Armature", "children": [ 1 ] }, { "name": "Bone", "mesh": 1 } ],
+        "scenes": [ { "name": "Scene", "nodes": [ 0 ] } ],
+        "materials": [ { "name": "Metal" } ]
+    }"#).unwrap();
+
+    // The first match wins when names are shared.
+    let (index, mesh) = root.mesh_by_name("Cube").unwrap();
+    assert_eq!(index.value(), 1);
+    assert_eq!(mesh.name.as_ref().map(|name| &**name), Some("Cube"));
+    assert!(root.mesh_by_name("Sphere").is_none());
+
+    assert_eq!(root.node_by_name("Bone").map(|(index, node)| (index.value(), node.mesh)),
+        Some((1, Some(gltf_json::Index::new(1)))));
+    assert_eq!(root.scene_by_name("Scene").map(|(index, _)| index.value()), Some(0));
+    assert_eq!(root.material_by_name("Metal").map(|(index, _)| index.value()), Some(0));
+    assert!(root.accessor_by_name("Cube").is_none());
+    assert!(root.animation_by_name("Cube").is_none());
+}
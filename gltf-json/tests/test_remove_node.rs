@@ -0,0 +1,93 @@
+use gltf_json::{Index, Root};
+
+const HIERARCHY: &str = r#"{
+    "asset": { "version": "2.0" },
+    "scenes": [ { "nodes": [ 0, 4 ] } ],
+    "nodes": [
+        { "translation": [ 0.0, 0.0, 0.0 ], "children": [ 1, 3 ] },
+        { "translation": [ 1.0, 0.0, 0.0 ], "children": [ 2 ] },
+        { "translation": [ 2.0, 0.0, 0.0 ] },
+        { "translation": [ 3.0, 0.0, 0.0 ] },
+        { "translation": [ 4.0, 0.0, 0.0 ] }
+    ],
+    "skins": [ { "joints": [ 3, 4 ], "skeleton": 1 } ]
+}"#;
+
+/// Identifies the remaining nodes by their original index, which is stored
+/// in the x component of their translation.
+fn original_indices(root: &Root) -> Vec<usize> {
+    root.nodes.iter().map(|node| node.translation.unwrap()[0] as usize).collect()
+}
+
+fn values(indices: &[Index<gltf_json::Node>]) -> Vec<usize> {
+    indices.iter().map(Index::value).collect()
+}
+
+#[test]
+fn test_remove_subtree() {
+    let mut root = Root::from_str(HIERARCHY).unwrap();
+    assert_eq!(root.remove_node(Index::new(1), false), Ok(()));
+    assert_eq!(original_indices(&root), [0, 3, 4]);
+    assert_eq!(values(root.nodes[0].children.as_ref().unwrap()), [1]);
+    assert_eq!(values(&root.scenes[0].nodes), [0, 2]);
+    assert_eq!(values(&root.skins[0].joints), [1, 2]);
+    assert!(root.skins[0].skeleton.is_none());
+}
+
+#[test]
+fn test_remove_and_relink() {
+    let mut root = Root::from_str(HIERARCHY).unwrap();
+    assert_eq!(root.remove_node(Index::new(1), true), Ok(()));
+    assert_eq!(original_indices(&root), [0, 2, 3, 4]);
+    assert_eq!(values(root.nodes[0].children.as_ref().unwrap()), [1, 2]);
+    assert_eq!(values(&root.scenes[0].nodes), [0, 3]);
+    assert_eq!(values(&root.skins[0].joints), [2, 3]);
+
+    // Relinking a scene root promotes its children to scene roots.
+    assert_eq!(root.remove_node(Index::new(0), true), Ok(()));
+    assert_eq!(original_indices(&root), [2, 3, 4]);
+    assert_eq!(values(&root.scenes[0].nodes), [0, 1, 2]);
+    assert_eq!(values(&root.skins[0].joints), [1, 2]);
+}
+
+#[test]
+fn test_remove_skin_joint() {
+    let mut root = Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "scenes": [ { "nodes": [ 0, 1, 3 ] } ],
+        "nodes": [
+            { "mesh": 0, "skin": 0 },
+            { "children": [ 2 ] },
+            {},
+            {}
+        ],
+        "meshes": [
+            { "primitives": [ { "attributes": { "POSITION": 0, "JOINTS_0": 1, "WEIGHTS_0": 2 } } ] }
+        ],
+        "skins": [ { "joints": [ 1, 2 ], "inverseBindMatrices": 3 } ]
+    }"#).unwrap();
+
+    // Removing a joint, directly or as part of a subtree, is refused.
+    assert_eq!(root.remove_node(Index::new(2), false), Err(Index::new(2)));
+    assert_eq!(root.remove_node(Index::new(1), false), Err(Index::new(1)));
+    assert_eq!(root.remove_node(Index::new(1), true), Err(Index::new(1)));
+    assert_eq!(root.nodes.len(), 4);
+    assert_eq!(values(&root.skins[0].joints), [1, 2]);
+
+    // Other nodes can be removed without changing the order of the joints.
+    assert_eq!(root.remove_node(Index::new(0), false), Ok(()));
+    assert_eq!(values(&root.skins[0].joints), [0, 1]);
+    assert_eq!(values(&root.scenes[0].nodes), [0, 2]);
+}
+
+#[test]
+fn test_parent_map() {
+    let root = Root::from_str(HIERARCHY).unwrap();
+    let parents = root.parent_map().unwrap();
+    let parents = parents.iter().map(|parent| parent.map(|index| index.value())).collect::<Vec<_>>();
+    assert_eq!(parents, [None, Some(0), Some(1), Some(0), None]);
+
+    let mut root = root;
+    root.nodes[4].children = Some(vec![Index::new(2)]);
+    assert_eq!(root.parent_map(), Err(Index::new(2)));
+}
@@ -0,0 +1,71 @@
+use gltf_json::{Root, Value};
+
+const MINIMAL: &str = r#"{
+    "asset": { "version": "2.0" },
+    "scene": 0,
+    "scenes": [ { "nodes": [ 0 ] } ],
+    "nodes": [ { "mesh": 0 } ],
+    "meshes": [ { "primitives": [ { "attributes": { "POSITION": 0 }, "material": 0 } ] } ],
+    "materials": [ { "normalTexture": { "index": 0 }, "occlusionTexture": { "index": 0 } } ],
+    "images": [ { "uri": "texture.png" } ],
+    "samplers": [ {} ],
+    "textures": [ { "sampler": 0, "source": 0 } ],
+    "buffers": [ { "byteLength": 36, "uri": "buffer.bin" } ],
+    "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+    "accessors": [
+        {
+            "bufferView": 0,
+            "componentType": 5126,
+            "count": 3,
+            "type": "VEC3",
+            "min": [ 0.0, 0.0, 0.0 ],
+            "max": [ 1.0, 1.0, 0.0 ]
+        }
+    ],
+    "animations": [
+        {
+            "channels": [ { "sampler": 0, "target": { "node": 0, "path": "translation" } } ],
+            "samplers": [ { "input": 0, "output": 0 } ]
+        }
+    ]
+}"#;
+
+fn value(json: &str) -> Value {
+    gltf_json::deserialize::from_str(json).unwrap()
+}
+
+#[test]
+fn test_serialize_minimal() {
+    let root = Root::from_str(MINIMAL).unwrap();
+    assert_eq!(root.to_value().unwrap(), value(MINIMAL));
+    assert!(!root.to_string().unwrap().contains("null"));
+}
+
+#[test]
+fn test_serialize_skips_defaults() {
+    let explicit = MINIMAL
+        .replace(
+            r#"{ "normalTexture": { "index": 0 }, "occlusionTexture": { "index": 0 } }"#,
+            r#"{
+                "alphaCutoff": 0.5,
+                "alphaMode": "OPAQUE",
+                "doubleSided": false,
+                "emissiveFactor": [ 0.0, 0.0, 0.0 ],
+                "pbrMetallicRoughness": {
+                    "baseColorFactor": [ 1.0, 1.0, 1.0, 1.0 ],
+                    "metallicFactor": 1.0,
+                    "roughnessFactor": 1.0
+                },
+                "normalTexture": { "index": 0, "scale": 1.0, "texCoord": 0 },
+                "occlusionTexture": { "index": 0, "strength": 1.0, "texCoord": 0 }
+            }"#,
+        )
+        .replace(r#""samplers": [ {} ]"#, r#""samplers": [ { "wrapS": 10497, "wrapT": 10497 } ]"#)
+        .replace(r#""bufferView": 0,"#, r#""bufferView": 0, "byteOffset": 0, "normalized": false,"#)
+        .replace(r#""input": 0, "output": 0"#, r#""input": 0, "interpolation": "LINEAR", "output": 0"#)
+        .replace(r#""material": 0"#, r#""material": 0, "mode": 4"#);
+    assert_ne!(value(&explicit), value(MINIMAL));
+
+    let root = Root::from_str(&explicit).unwrap();
+    assert_eq!(root.to_value().unwrap(), value(MINIMAL));
+}
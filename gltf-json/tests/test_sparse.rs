@@ -0,0 +1,102 @@
+const SPARSE: &str = r#"{
+    "asset": { "version": "2.0" },
+    "buffers": [ { "byteLength": 32 } ],
+    "bufferViews": [
+        { "buffer": 0, "byteLength": 20 },
+        { "buffer": 0, "byteOffset": 20, "byteLength": 4 },
+        { "buffer": 0, "byteOffset": 24, "byteLength": 8 }
+    ],
+    "accessors": [
+        {
+            "bufferView": 0,
+            "componentType": 5126,
+            "count": 5,
+            "type": "SCALAR",
+            "sparse": {
+                "count": 2,
+                "indices": { "bufferView": 1, "byteOffset": 0, "componentType": 5123 },
+                "values": { "bufferView": 2, "byteOffset": 0 }
+            }
+        }
+    ]
+}"#;
+
+#[test]
+fn test_sparse_indices_roundtrip() {
+    let json = gltf_json::Root::from_str(SPARSE).unwrap();
+    let indices = &json.accessors[0].sparse.as_ref().unwrap().indices;
+    assert_eq!(indices.buffer_view.value(), 1);
+    assert_eq!(indices.byte_offset, 0);
+
+    let value = json.to_value().unwrap();
+    let indices = &value["accessors"][0]["sparse"]["indices"];
+    assert_eq!(indices["bufferView"], 1);
+    assert_eq!(indices["componentType"], 5123);
+    // The default byte offset is not written.
+    assert!(indices.get("byteOffset").is_none());
+
+    let roundtrip = gltf_json::Root::from_value(value).unwrap();
+    let indices = &roundtrip.accessors[0].sparse.as_ref().unwrap().indices;
+    assert_eq!(indices.buffer_view.value(), 1);
+    assert_eq!(indices.byte_offset, 0);
+}
+
+#[test]
+fn test_sparse_storage() {
+    let json = gltf_json::Root::from_str(SPARSE).unwrap();
+    let sparse = json.accessors[0].sparse.as_ref().unwrap();
+    assert_eq!(sparse.count, 2);
+    assert_eq!(sparse.indices.buffer_view.value(), 1);
+    assert_eq!(sparse.values.buffer_view.value(), 2);
+    assert_eq!(sparse.values.byte_offset, 0);
+}
+
+#[test]
+fn test_sparse_indices_distinct_keys() {
+    use gltf_json::accessor::{sparse, ComponentType};
+    use gltf_json::validation::Checked;
+
+    let indices: sparse::Indices = gltf_json::deserialize::from_str(
+        r#"{ "bufferView": 2, "byteOffset": 8, "componentType": 5125 }"#,
+    ).unwrap();
+    assert_eq!(indices.buffer_view.value(), 2);
+    assert_eq!(indices.byte_offset, 8);
+    match indices.component_type {
+        Checked::Valid(sparse_type) => assert_eq!(sparse_type.0, ComponentType::U32),
+        Checked::Invalid => panic!("expected a valid component type"),
+    }
+}
+
+#[test]
+fn test_sparse_values_roundtrip() {
+    use gltf_json::accessor::sparse;
+
+    let values: sparse::Values = gltf_json::deserialize::from_str(
+        r#"{ "bufferView": 3, "byteOffset": 12 }"#,
+    ).unwrap();
+    let value = gltf_json::serialize::to_value(&values).unwrap();
+    assert_eq!(value["bufferView"], 3);
+    assert_eq!(value["byteOffset"], 12);
+
+    let roundtrip: sparse::Values = gltf_json::deserialize::from_value(value).unwrap();
+    assert_eq!(roundtrip.buffer_view.value(), 3);
+    assert_eq!(roundtrip.byte_offset, 12);
+}
+
+#[test]
+fn test_sparse_indices_component_type_validate() {
+    use gltf_json::validation::{Error, Validate};
+    use gltf_json::Path;
+
+    for &component_type in &["5120", "5122", "5126"] {
+        let json = gltf_json::Root::from_str(&SPARSE.replace("5123", component_type)).unwrap();
+        let mut errs = vec![];
+        json.validate(
+            &json,
+            gltf_json::Path::new,
+            &mut |path, err| errs.push((path(), err)),
+        );
+        assert_eq!(errs,
+            [(Path("accessors[0].sparse.indices.componentType".into()), Error::Invalid)]);
+    }
+}
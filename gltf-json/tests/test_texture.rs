@@ -0,0 +1,76 @@
+#[test]
+fn test_texture() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "texture.png" } ],
+        "samplers": [ { "magFilter": 9729 } ],
+        "textures": [ { "sampler": 0, "source": 0 }, { "source": 0 }, {} ]
+    }"#).unwrap();
+    assert_eq!(json.textures[0].sampler.map(|x| x.value()), Some(0));
+    assert_eq!(json.textures[0].source.map(|x| x.value()), Some(0));
+    assert!(json.textures[1].sampler.is_none());
+    assert!(json.textures[2].source.is_none());
+
+    // Only glTF 2.0 properties are written.
+    let value = json.to_value().unwrap();
+    let keys = value["textures"][0]
+        .as_object()
+        .unwrap()
+        .keys()
+        .filter(|key| *key != "extras")
+        .cloned()
+        .collect::<Vec<_>>();
+    assert_eq!(keys, ["sampler", "source"]);
+    assert!(value["textures"][2].get("source").is_none());
+}
+
+#[test]
+fn test_texture_roundtrip() {
+    // glTF 1.0 texture properties are not part of the 2.0 schema.
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "texture.png" } ],
+        "textures": [
+            {
+                "source": 0,
+                "format": 6408,
+                "internalFormat": 6408,
+                "target": 3553,
+                "type": 5121
+            }
+        ]
+    }"#).unwrap();
+    let string = json.to_string().unwrap();
+    assert!(string.contains(r#""textures":[{"source":0"#));
+    for property in &["format", "internalFormat", "target", "type"] {
+        assert!(!string.contains(property));
+    }
+
+    let reimported = gltf_json::Root::from_str(&string).unwrap();
+    assert_eq!(reimported.to_string().unwrap(), string);
+}
+
+#[test]
+fn test_sampler_gl_enums() {
+    use gltf_json::texture::{MagFilter, MinFilter, WrappingMode};
+
+    assert_eq!(MagFilter::Nearest.as_gl_enum(), 9728);
+    assert_eq!(MagFilter::Linear.as_gl_enum(), 9729);
+
+    let min_filters = [
+        MinFilter::Nearest,
+        MinFilter::Linear,
+        MinFilter::NearestMipmapNearest,
+        MinFilter::LinearMipmapNearest,
+        MinFilter::NearestMipmapLinear,
+        MinFilter::LinearMipmapLinear,
+    ].iter().map(MinFilter::as_gl_enum).collect::<Vec<_>>();
+    assert_eq!(min_filters, [9728, 9729, 9984, 9985, 9986, 9987]);
+
+    let wrapping_modes = [
+        WrappingMode::ClampToEdge,
+        WrappingMode::MirroredRepeat,
+        WrappingMode::Repeat,
+    ].iter().map(WrappingMode::as_gl_enum).collect::<Vec<_>>();
+    assert_eq!(wrapping_modes, [33071, 33648, 10497]);
+}
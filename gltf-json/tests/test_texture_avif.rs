@@ -0,0 +1,34 @@
+#![cfg(feature = "EXT_texture_avif")]
+
+use gltf_json::validation::{Error, Validate};
+use gltf_json::Path;
+
+const TEXTURE_AVIF: &str = r#"{
+    "asset": { "version": "2.0" },
+    "extensionsUsed": [ "EXT_texture_avif" ],
+    "images": [ { "uri": "fallback.png" }, { "uri": "texture.avif" } ],
+    "textures": [
+        { "source": 0, "extensions": { "EXT_texture_avif": { "source": 1 } } },
+        { "source": 0, "extensions": { "EXT_texture_avif": { "source": 2 } } }
+    ]
+}"#;
+
+#[test]
+fn test_texture_avif() {
+    let json = gltf_json::Root::from_str(TEXTURE_AVIF).unwrap();
+    let avif = json.textures[0]
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.ext_texture_avif.as_ref())
+        .unwrap();
+    assert_eq!(avif.source.value(), 1);
+
+    let mut errs = vec![];
+    json.validate(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs,
+        [(Path("textures[1].extensions.extTextureAvif.source".into()), Error::IndexOutOfBounds { len: 2 })]);
+}
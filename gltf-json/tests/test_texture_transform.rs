@@ -0,0 +1,51 @@
+#![cfg(feature = "KHR_texture_transform")]
+
+#[test]
+fn test_texture_transform() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_texture_transform" ],
+        "materials": [
+            {
+                "pbrMetallicRoughness": {
+                    "baseColorTexture": {
+                        "index": 0,
+                        "extensions": {
+                            "KHR_texture_transform": {
+                                "offset": [ 0.5, 0.25 ],
+                                "rotation": 1.5,
+                                "scale": [ 2.0, 4.0 ],
+                                "texCoord": 1
+                            }
+                        }
+                    }
+                },
+                "emissiveTexture": {
+                    "index": 0,
+                    "extensions": { "KHR_texture_transform": {} }
+                }
+            }
+        ]
+    }"#).unwrap();
+    let material = &json.materials[0];
+    let info = material.pbr_metallic_roughness.base_color_texture.as_ref().unwrap();
+    let transform = info.extensions.as_ref().unwrap().texture_transform.as_ref().unwrap();
+    assert_eq!(transform.offset.0, [0.5, 0.25]);
+    assert_eq!(transform.rotation, 1.5);
+    assert_eq!(transform.scale.0, [2.0, 4.0]);
+    assert_eq!(transform.tex_coord, Some(1));
+
+    // Omitted properties take their defaults.
+    let info = material.emissive_texture.as_ref().unwrap();
+    let transform = info.extensions.as_ref().unwrap().texture_transform.as_ref().unwrap();
+    assert_eq!(transform.offset.0, [0.0, 0.0]);
+    assert_eq!(transform.rotation, 0.0);
+    assert_eq!(transform.scale.0, [1.0, 1.0]);
+    assert_eq!(transform.tex_coord, None);
+
+    let value = json.to_value().unwrap();
+    let transform = &value["materials"][0]["pbrMetallicRoughness"]["baseColorTexture"]
+        ["extensions"]["KHR_texture_transform"];
+    assert_eq!(transform["scale"][1], 4.0);
+    assert_eq!(transform["texCoord"], 1);
+}
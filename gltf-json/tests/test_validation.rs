@@ -1,25 +1,402 @@
-use std::{fs, io};
+use std::fs;
 
 use gltf_json::validation::{Validate, Error};
 use gltf_json::Path;
 
-fn import_json(filename: &str) -> gltf_json::Root {
-    let file = fs::File::open(filename).unwrap();
-    let reader = io::BufReader::new(file);
-    gltf_json::Root::from_reader(reader).unwrap()
+fn validate(json: &str) -> Vec<(Path, Error)> {
+    let root = gltf_json::Root::from_str(json).unwrap();
+    let mut errs = vec![];
+    root.validate(&root, gltf_json::Path::new, &mut |path, err| errs.push((path(), err)));
+    errs
 }
 
 #[test]
 fn test_accessor_bounds_validate() {
     // file with missing min/max values
-    let json = import_json("tests/minimal_accessor_invalid.gltf");
-    let mut errs = vec![];
-    json.validate(
-        &json,
-        gltf_json::Path::new,
-        &mut |path, err| errs.push((path(), err)),
-    );
-    assert_eq!(errs,
+    let json = fs::read_to_string("tests/minimal_accessor_invalid.gltf").unwrap();
+    assert_eq!(validate(&json),
         [(Path("meshes[0].primitives[0].attributes[\"POSITION\"].min".into()), Error::Missing),
          (Path("meshes[0].primitives[0].attributes[\"POSITION\"].max".into()), Error::Invalid)]);
 }
+
+#[test]
+fn test_duplicate_keys() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "meshes": [],
+        "nodes": [],
+        "meshes": [ { "primitives": [] } ]
+    }"#;
+    assert_eq!(gltf_json::Root::duplicate_keys(json).unwrap(), vec!["meshes".to_string()]);
+    assert!(gltf_json::Root::duplicate_keys(br#"{ "asset": { "version": "2.0" } }"#).unwrap().is_empty());
+}
+
+#[test]
+fn test_validate_strict() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 0 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 0, "byteStride": 2 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 0, "type": "SCALAR" }
+        ],
+        "materials": [
+            {
+                "pbrMetallicRoughness": { "baseColorFactor": [ 1.0, 2.0, 1.0, 1.0 ], "metallicFactor": -0.5 },
+                "alphaCutoff": -1.0
+            }
+        ],
+        "cameras": [
+            { "type": "perspective", "perspective": { "yfov": 0.0, "znear": 1.0, "zfar": 0.5 } }
+        ]
+    }"#;
+
+    // The document passes the usual checks.
+    assert!(validate(json).is_empty());
+
+    let path = |s: &str| Path(s.into());
+    assert_eq!(gltf_json::Root::from_str(json).unwrap().validate_strict(), [
+        (path("accessors[0].count"), Error::Invalid),
+        (path("buffers[0].byteLength"), Error::Invalid),
+        (path("bufferViews[0].byteLength"), Error::Invalid),
+        (path("bufferViews[0].byteStride"), Error::Invalid),
+        (path("materials[0].pbrMetallicRoughness.baseColorFactor"), Error::Invalid),
+        (path("materials[0].pbrMetallicRoughness.metallicFactor"), Error::Invalid),
+        (path("materials[0].alphaCutoff"), Error::Invalid),
+        (path("cameras[0].perspective.yfov"), Error::Invalid),
+        (path("cameras[0].perspective.zfar"), Error::Invalid),
+    ]);
+}
+
+#[test]
+fn test_accessor_buffer_view_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 16 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 16 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR" },
+            {
+                "bufferView": 12,
+                "componentType": 5126,
+                "count": 1,
+                "type": "SCALAR",
+                "sparse": {
+                    "count": 1,
+                    "indices": { "bufferView": 3, "componentType": 5123 },
+                    "values": { "bufferView": 4 }
+                }
+            }
+        ]
+    }"#);
+    assert_eq!(errs,
+        [(Path("accessors[1].bufferView".into()), Error::IndexOutOfBounds { len: 1 }),
+         (Path("accessors[1].sparse.indices.bufferView".into()), Error::IndexOutOfBounds { len: 1 }),
+         (Path("accessors[1].sparse.values.bufferView".into()), Error::IndexOutOfBounds { len: 1 })]);
+}
+
+#[test]
+fn test_cross_references_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "scene": 1,
+        "scenes": [ { "nodes": [ 0, 7 ] } ],
+        "nodes": [ { "mesh": 2, "camera": 0, "skin": 1, "children": [ 9 ] } ],
+        "meshes": [
+            {
+                "primitives": [
+                    {
+                        "attributes": { "POSITION": 5 },
+                        "indices": 6,
+                        "material": 1,
+                        "targets": [ { "NORMAL": 8 } ]
+                    }
+                ]
+            }
+        ],
+        "skins": [ { "joints": [ 3 ], "skeleton": 4 } ],
+        "textures": [ { "sampler": 2, "source": 1 } ],
+        "animations": [
+            {
+                "channels": [ { "sampler": 0, "target": { "node": 6, "path": "translation" } } ],
+                "samplers": [ { "input": 0, "output": 1 } ]
+            }
+        ]
+    }"#);
+    let mut paths = errs
+        .into_iter()
+        .inspect(|&(_, err)| assert!(matches!(err, Error::IndexOutOfBounds { .. })))
+        .map(|(path, _)| path.0)
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, [
+        "animations[0].channels[0].target.node",
+        "animations[0].samplers[0].input",
+        "animations[0].samplers[0].output",
+        "meshes[0].primitives[0].attributes[\"POSITION\"]",
+        "meshes[0].primitives[0].indices",
+        "meshes[0].primitives[0].material",
+        "meshes[0].primitives[0].targets[0].normals",
+        "nodes[0].camera",
+        "nodes[0].children[0]",
+        "nodes[0].mesh",
+        "nodes[0].skin",
+        "scene",
+        "scenes[0].nodes[1]",
+        "skins[0].joints[0]",
+        "skins[0].skeleton",
+        "textures[0].sampler",
+        "textures[0].source",
+    ]);
+}
+
+#[test]
+fn test_buffer_view_buffer_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 4 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 4 }, { "buffer": 5, "byteLength": 4 } ]
+    }"#);
+    assert_eq!(errs, [(Path("bufferViews[1].buffer".into()), Error::IndexOutOfBounds { len: 1 })]);
+}
+
+#[test]
+fn test_buffer_view_fits_buffer_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 8 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 8 },
+            { "buffer": 0, "byteOffset": 4, "byteLength": 4 },
+            { "buffer": 0, "byteLength": 12 },
+            { "buffer": 0, "byteOffset": 8, "byteLength": 1 }
+        ]
+    }"#);
+    assert_eq!(errs,
+        [(Path("bufferViews[2]".into()), Error::Invalid),
+         (Path("bufferViews[3]".into()), Error::Invalid)]);
+}
+
+#[test]
+fn test_node_self_reference_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [ { "children": [ 1 ] }, { "children": [ 1 ] } ]
+    }"#);
+    assert_eq!(errs, [(Path("nodes[1].children[0]".into()), Error::Invalid)]);
+}
+
+#[test]
+fn test_camera_projection_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "cameras": [
+            { "type": "perspective", "orthographic": { "xmag": 1.0, "ymag": 1.0, "zfar": 2.0, "znear": 1.0 } },
+            { "type": "orthographic", "perspective": { "yfov": 1.0, "znear": 1.0 } },
+            { "type": "perspective", "perspective": { "yfov": 1.0, "znear": 1.0 } }
+        ]
+    }"#);
+    assert_eq!(errs,
+        [(Path("cameras[0].perspective".into()), Error::Missing),
+         (Path("cameras[1].orthographic".into()), Error::Missing)]);
+}
+
+#[test]
+fn test_accessor_fits_buffer_view_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 48 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 36 },
+            { "buffer": 0, "byteLength": 48, "byteStride": 16 }
+        ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3" },
+            { "bufferView": 0, "byteOffset": 4, "componentType": 5126, "count": 3, "type": "VEC3" },
+            { "bufferView": 1, "byteOffset": 4, "componentType": 5126, "count": 3, "type": "VEC3" },
+            { "bufferView": 1, "byteOffset": 8, "componentType": 5126, "count": 3, "type": "VEC3" }
+        ]
+    }"#);
+    assert_eq!(errs,
+        [(Path("accessors[1]".into()), Error::Invalid),
+         (Path("accessors[2]".into()), Error::Invalid),
+         (Path("accessors[4]".into()), Error::Invalid)]);
+}
+
+#[test]
+fn test_node_matrix_and_trs_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "matrix": [ 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1 ] },
+            { "translation": [ 1.0, 0.0, 0.0 ], "scale": [ 2.0, 2.0, 2.0 ] },
+            {
+                "matrix": [ 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1 ],
+                "rotation": [ 0.0, 0.0, 0.0, 1.0 ]
+            }
+        ]
+    }"#);
+    assert_eq!(errs, [(Path("nodes[2].matrix".into()), Error::Invalid)]);
+}
+
+#[test]
+fn test_node_hierarchy_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "children": [ 1 ] },
+            { "children": [ 2 ] },
+            { "children": [ 1 ] },
+            { "children": [ 4 ] },
+            { "children": [ 3 ] },
+            { "children": [ 7 ] },
+            { "children": [ 7 ] },
+            {}
+        ]
+    }"#);
+    assert_eq!(errs,
+        [(Path("nodes[2].children[0]".into()), Error::Invalid),
+         (Path("nodes[6].children[0]".into()), Error::Invalid),
+         (Path("nodes[4].children[0]".into()), Error::Invalid)]);
+}
+
+#[test]
+fn test_node_mesh_validate() {
+    let json = r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "children": [ 1 ] },
+            { "mesh": 0 },
+            { "mesh": 1 }
+        ],
+        "meshes": [ { "primitives": [] } ]
+    }"#;
+    let root = gltf_json::Root::from_str(json).unwrap();
+    assert!(root.nodes[0].mesh.is_none());
+    assert_eq!(root.nodes[1].mesh.map(|index| index.value()), Some(0));
+
+    // Only meshes that are present are range-checked.
+    assert_eq!(validate(json), [(Path("nodes[2].mesh".into()), Error::IndexOutOfBounds { len: 1 })]);
+
+    let string = gltf_json::serialize::to_string(&root.nodes[0]).unwrap();
+    assert!(!string.contains("mesh"));
+}
+
+#[test]
+fn test_asset_version_validate() {
+    use gltf_json::asset::parse_version;
+
+    assert_eq!(parse_version("2.0"), Some((2, 0)));
+    assert_eq!(parse_version("2"), Some((2, 0)));
+    assert_eq!(parse_version("10.12"), Some((10, 12)));
+    for version in &["", "2.", ".0", "2.0.1", "v2.0", "2.x", "+2.0"] {
+        assert_eq!(parse_version(version), None, "{:?}", version);
+    }
+
+    let validate = |asset: &str| validate(&format!(r#"{{ "asset": {} }}"#, asset));
+    assert!(validate(r#"{ "version": "2.0", "minVersion": "2.0" }"#).is_empty());
+    assert_eq!(validate(r#"{ "version": "two" }"#),
+        [(Path("asset.version".into()), Error::InvalidVersion)]);
+    assert_eq!(validate(r#"{ "version": "2.0", "minVersion": "2.0-beta" }"#),
+        [(Path("asset.minVersion".into()), Error::InvalidVersion)]);
+    assert_eq!(validate(r#"{ "version": "2.0", "minVersion": "2.1" }"#),
+        [(Path("asset.minVersion".into()), Error::Invalid)]);
+}
+
+#[test]
+fn test_primitive_attribute_count_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 48 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 48 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3", "min": [ 0, 0, 0 ], "max": [ 1, 1, 1 ] },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2" }
+        ],
+        "meshes": [
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 } },
+                    { "attributes": { "POSITION": 0, "TEXCOORD_0": 1 } }
+                ]
+            }
+        ]
+    }"#);
+    assert_eq!(errs, [
+        (Path("meshes[0].primitives[1].attributes[\"TEXCOORD_0\"]".into()), Error::Invalid),
+    ]);
+}
+
+#[test]
+fn test_mesh_weights_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 36 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [ 0, 0, 0 ], "max": [ 1, 1, 1 ] }
+        ],
+        "meshes": [
+            { "primitives": [ { "attributes": { "POSITION": 0 }, "targets": [ {} ] } ], "weights": [ 0.5 ] },
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 }, "targets": [ {} ] },
+                    { "attributes": { "POSITION": 0 } }
+                ],
+                "weights": [ 0.5, 0.5 ]
+            },
+            { "primitives": [ { "attributes": { "POSITION": 0 }, "targets": [ {} ] } ] }
+        ]
+    }"#);
+    assert_eq!(errs, [
+        (Path("meshes[1].primitives[0].targets".into()), Error::Invalid),
+        (Path("meshes[1].primitives[1].targets".into()), Error::Invalid),
+    ]);
+}
+
+#[test]
+fn test_node_weights_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 36 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [ 0, 0, 0 ], "max": [ 1, 1, 1 ] }
+        ],
+        "meshes": [
+            { "primitives": [ { "attributes": { "POSITION": 0 }, "targets": [ {}, {} ] } ] },
+            { "primitives": [ { "attributes": { "POSITION": 0 } } ] },
+            {
+                "primitives": [
+                    { "attributes": { "POSITION": 0 }, "targets": [ {} ] },
+                    { "attributes": { "POSITION": 0 }, "targets": [ {}, {} ] }
+                ]
+            }
+        ],
+        "nodes": [
+            { "mesh": 0, "weights": [ 0.25, 0.75 ] },
+            { "mesh": 0, "weights": [ 1.0 ] },
+            { "mesh": 1, "weights": [ 1.0 ] },
+            { "mesh": 1 },
+            { "weights": [ 1.0 ] },
+            { "mesh": 2, "weights": [ 1.0 ] }
+        ]
+    }"#);
+    assert_eq!(errs, [
+        (Path("nodes[1].weights".into()), Error::Invalid),
+        (Path("nodes[2].weights".into()), Error::Invalid),
+        (Path("nodes[4].weights".into()), Error::Invalid),
+        (Path("nodes[5].weights".into()), Error::Invalid),
+    ]);
+}
+
+#[test]
+fn test_extensions_required_validate() {
+    let errs = validate(r#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_materials_unlit" ],
+        "extensionsRequired": [ "KHR_materials_unlit", "KHR_draco_mesh_compression" ]
+    }"#);
+    assert_eq!(errs, [(Path("extensionsRequired[1]".into()), Error::Invalid)]);
+}
@@ -69,7 +69,7 @@ pub mod sparse;
 
 #[cfg(feature = "utils")]
 #[doc(inline)]
-pub use self::util::{Item, Iter};
+pub use self::util::{F32Iter, Item, Iter, SparseOverrides};
 
 /// A typed view into a buffer view.
 #[derive(Clone, Debug)]
@@ -149,10 +149,54 @@ impl<'a> Accessor<'a> {
         self.json.max.clone()
     }
 
+    /// Returns the minimum value of each component as floating point numbers.
+    ///
+    /// Returns `None` if `min` is absent or does not contain one number per
+    /// component.
+    pub fn min_f32(&self) -> Option<Vec<f32>> {
+        self.components(self.json.min.as_ref(), |x| x.as_f64().map(|x| x as f32))
+    }
+
+    /// Returns the maximum value of each component as floating point numbers.
+    ///
+    /// Returns `None` if `max` is absent or does not contain one number per
+    /// component.
+    pub fn max_f32(&self) -> Option<Vec<f32>> {
+        self.components(self.json.max.as_ref(), |x| x.as_f64().map(|x| x as f32))
+    }
+
+    /// Returns the minimum value of each component as integers.
+    ///
+    /// Returns `None` if `min` is absent, does not contain one number per
+    /// component, or contains non-integer values.
+    pub fn min_i64(&self) -> Option<Vec<i64>> {
+        self.components(self.json.min.as_ref(), json::Value::as_i64)
+    }
+
+    /// Returns the maximum value of each component as integers.
+    ///
+    /// Returns `None` if `max` is absent, does not contain one number per
+    /// component, or contains non-integer values.
+    pub fn max_i64(&self) -> Option<Vec<i64>> {
+        self.components(self.json.max.as_ref(), json::Value::as_i64)
+    }
+
+    /// Interprets a `min` or `max` array according to the accessor dimensions.
+    fn components<T, F>(&self, value: Option<&json::Value>, f: F) -> Option<Vec<T>>
+    where
+        F: Fn(&json::Value) -> Option<T>,
+    {
+        let array = value?.as_array()?;
+        if array.len() != self.dimensions().multiplicity() {
+            return None;
+        }
+        array.iter().map(f).collect()
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// Specifies whether integer data values should be normalized.
@@ -167,4 +211,38 @@ impl<'a> Accessor<'a> {
             sparse::Sparse::new(self.document, json)
         })
     }
+
+    /// Returns the bytes of the buffer view region spanned by the elements of
+    /// the accessor, including any interleaved data between them.
+    ///
+    /// Sparse substitutions are not applied. Returns `None` if the buffer data
+    /// is unavailable or too short.
+    #[cfg(feature = "utils")]
+    pub fn data<'s, F>(&self, get_buffer_data: F) -> Option<&'s [u8]>
+        where F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let view = self.view();
+        let stride = view.stride().unwrap_or(self.size());
+        let length = match self.count() {
+            0 => 0,
+            count => (count - 1) * stride + self.size(),
+        };
+        let data = get_buffer_data(view.buffer())?.get(view.offset()..view.offset() + view.length())?;
+        data.get(self.offset()..self.offset() + length)
+    }
+
+    /// Reads the elements of a `VEC3` accessor with `f32` components, such as
+    /// `POSITION` or `NORMAL` data.
+    ///
+    /// Returns `None` if the accessor has some other data type or dimensions,
+    /// or if the buffer data is unavailable.
+    #[cfg(feature = "utils")]
+    pub fn read_vec3_f32<'s, F>(&self, get_buffer_data: F) -> Option<Vec<[f32; 3]>>
+        where F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        if (self.data_type(), self.dimensions()) != (DataType::F32, Dimensions::Vec3) {
+            return None;
+        }
+        Iter::new(self.clone(), get_buffer_data).map(Iterator::collect)
+    }
 }
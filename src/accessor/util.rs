@@ -3,6 +3,7 @@ use byteorder::{LE, ByteOrder};
 use std::marker::PhantomData;
 
 use crate::{accessor, buffer};
+use crate::accessor::{DataType, Dimensions};
 
 fn buffer_view_slice<'a, 's>(
     view: buffer::View<'a>,
@@ -14,6 +15,51 @@ fn buffer_view_slice<'a, 's>(
         .map(|slice| &slice[start..end])
 }
 
+fn sparse_indices_iter<'a, 's>(
+    sparse: &accessor::sparse::Sparse<'a>,
+    get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+) -> Option<SparseIndicesIter<'s>> {
+    let indices = sparse.indices();
+    let sparse_count = sparse.count() as usize;
+    let view = indices.view();
+    let index_size = indices.index_type().size();
+    let stride = view.stride().unwrap_or(index_size);
+    let subslice = if let Some(slice) = buffer_view_slice(view, get_buffer_data) {
+        let start = indices.offset() as usize;
+        let end = start + stride * (sparse_count - 1) + index_size;
+        &slice[start..end]
+    } else {
+        return None
+    };
+    Some(match indices.index_type() {
+        accessor::sparse::IndexType::U8 => SparseIndicesIter::U8(ItemIter::new(subslice, stride)),
+        accessor::sparse::IndexType::U16 => SparseIndicesIter::U16(ItemIter::new(subslice, stride)),
+        accessor::sparse::IndexType::U32 => SparseIndicesIter::U32(ItemIter::new(subslice, stride)),
+    })
+}
+
+fn sparse_iters<'a, 's, T: Item>(
+    sparse: &accessor::sparse::Sparse<'a>,
+    get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+) -> Option<(SparseIndicesIter<'s>, ItemIter<'s, T>)> {
+    let values = sparse.values();
+    let sparse_count = sparse.count() as usize;
+    let index_iter = sparse_indices_iter(sparse, get_buffer_data)?;
+    let value_iter = {
+        let view = values.view();
+        let stride = view.stride().unwrap_or(mem::size_of::<T>());
+        let subslice = if let Some(slice) = buffer_view_slice(view, get_buffer_data) {
+            let start = values.offset() as usize;
+            let end = start + stride * (sparse_count - 1) + mem::size_of::<T>();
+            &slice[start..end]
+        } else {
+            return None
+        };
+        ItemIter::new(subslice, stride)
+    };
+    Some((index_iter, value_iter))
+}
+
 /// General iterator for an accessor.
 #[derive(Clone, Debug)]
 pub enum Iter<'a, T: Item> {
@@ -24,6 +70,7 @@ pub enum Iter<'a, T: Item> {
     Sparse(SparseIter<'a, T>),
 }
 
+impl<'a, T: Item> ExactSizeIterator for Iter<'a, T> {}
 impl<'a, T: Item> Iterator for Iter<'a, T> {
     type Item = T;
 
@@ -33,6 +80,13 @@ impl<'a, T: Item> Iterator for Iter<'a, T> {
             &mut Iter::Sparse(ref mut iter) => iter.next(),
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            Iter::Standard(ref iter) => iter.size_hint(),
+            Iter::Sparse(ref iter) => iter.size_hint(),
+        }
+    }
 }
 
 /// Iterator over indices of sparse accessor.
@@ -89,6 +143,44 @@ impl<'a, T: Item> SparseIter<'a, T> {
     }
 }
 
+/// Visits the `(index, value)` pairs that a sparse accessor overrides,
+/// without reading the base values.
+#[derive(Clone, Debug)]
+pub struct SparseOverrides<'a, T: Item> {
+    /// Sparse indices iterator.
+    indices: SparseIndicesIter<'a>,
+
+    /// Sparse values iterator.
+    values: ItemIter<'a, T>,
+}
+
+impl<'a, 's, T: Item> SparseOverrides<'s, T> {
+    /// Constructor.
+    ///
+    /// Returns `None` if the accessor is not sparse or if the buffer data
+    /// holding the sparse indices or values is unavailable.
+    pub fn new<F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+    ) -> Option<SparseOverrides<'s, T>>
+        where F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let sparse = accessor.sparse()?;
+        let (indices, values) = sparse_iters(&sparse, &get_buffer_data)?;
+        Some(SparseOverrides { indices, values })
+    }
+}
+
+impl<'a, T: Item> Iterator for SparseOverrides<'a, T> {
+    type Item = (u32, T);
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.indices.next(), self.values.next()) {
+            (Some(index), Some(value)) => Some((index, value)),
+            _ => None,
+        }
+    }
+}
+
 impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -110,12 +202,156 @@ impl<'a, T: Item> Iterator for SparseIter<'a, T> {
 
         Some(next_value)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.base.size_hint()
+    }
+}
+
+/// Visits the elements of an [`Accessor`] of any data type as `f32`
+/// components.
+///
+/// Each element is yielded as a `Vec` with one entry per component. Integer
+/// components are converted to the `[0.0, 1.0]` or `[-1.0, 1.0]` range when
+/// the accessor is normalized and are cast as-is otherwise. Sparse values are
+/// applied.
+///
+/// [`Accessor`]: struct.Accessor.html
+#[derive(Clone, Debug)]
+pub struct F32Iter<'a> {
+    /// Base data, starting at the first element.
+    data: &'a [u8],
+
+    /// Distance in bytes between the start of consecutive base elements.
+    stride: usize,
+
+    /// The component data type.
+    data_type: accessor::DataType,
+
+    /// Whether integer components are normalized.
+    normalized: bool,
+
+    /// Number of components per element.
+    components: usize,
+
+    /// Number of elements.
+    count: usize,
+
+    /// Iterator counter.
+    counter: usize,
+
+    /// Sparse indices iterator, if the accessor is sparse.
+    sparse_indices: Option<iter::Peekable<SparseIndicesIter<'a>>>,
+
+    /// Tightly packed sparse values.
+    sparse_values: &'a [u8],
+}
+
+impl<'a, 's> F32Iter<'s> {
+    /// Constructor.
+    ///
+    /// Returns `None` if the buffer data backing the accessor is unavailable.
+    pub fn new<F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+    ) -> Option<F32Iter<'s>>
+        where F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let size = accessor.size();
+        let view = accessor.view();
+        let stride = view.stride().unwrap_or(size);
+        let data = buffer_view_slice(view, &get_buffer_data)?.get(accessor.offset()..)?;
+        let (sparse_indices, sparse_values) = match accessor.sparse() {
+            Some(sparse) => {
+                let indices = sparse_indices_iter(&sparse, &get_buffer_data)?;
+                let values = sparse.values();
+                let slice = buffer_view_slice(values.view(), &get_buffer_data)?;
+                let values = slice.get(values.offset() as usize..)?;
+                (Some(indices.peekable()), values)
+            },
+            None => (None, &[][..]),
+        };
+        Some(F32Iter {
+            data,
+            stride,
+            data_type: accessor.data_type(),
+            normalized: accessor.normalized(),
+            components: accessor.dimensions().multiplicity(),
+            count: accessor.count(),
+            counter: 0,
+            sparse_indices,
+            sparse_values,
+        })
+    }
+
+    /// Decodes the element starting at the beginning of `slice`.
+    fn read(&self, slice: &[u8]) -> Option<Vec<f32>> {
+        let component_size = self.data_type.size();
+        if slice.len() < self.components * component_size {
+            return None;
+        }
+        let element = slice
+            .chunks(component_size)
+            .take(self.components)
+            .map(|component| {
+                use crate::accessor::DataType::*;
+                use crate::Normalize;
+                match (self.data_type, self.normalized) {
+                    (I8, true) => i8::from_slice(component).normalize(),
+                    (U8, true) => u8::from_slice(component).normalize(),
+                    (I16, true) => i16::from_slice(component).normalize(),
+                    (U16, true) => u16::from_slice(component).normalize(),
+                    (I8, false) => i8::from_slice(component) as f32,
+                    (U8, false) => u8::from_slice(component) as f32,
+                    (I16, false) => i16::from_slice(component) as f32,
+                    (U16, false) => u16::from_slice(component) as f32,
+                    (U32, _) => u32::from_slice(component) as f32,
+                    (F32, _) => f32::from_slice(component),
+                }
+            })
+            .collect();
+        Some(element)
+    }
+}
+
+impl<'a> Iterator for F32Iter<'a> {
+    type Item = Vec<f32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.count {
+            return None;
+        }
+        let index = self.counter;
+        self.counter += 1;
+
+        if let Some(indices) = self.sparse_indices.as_mut() {
+            if indices.peek() == Some(&(index as u32)) {
+                indices.next();
+                let size = self.components * self.data_type.size();
+                let (value, rest) = self.sparse_values.split_at(size.min(self.sparse_values.len()));
+                self.sparse_values = rest;
+                return self.read(value);
+            }
+        }
+        self.read(self.data.get(index * self.stride..)?)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.count - self.counter;
+        (hint, Some(hint))
+    }
 }
 
 /// Represents items that can be read by an [`Accessor`].
 ///
 /// [`Accessor`]: struct.Accessor.html
 pub trait Item {
+    /// The data type of the components of this item.
+    const DATA_TYPE: DataType;
+
+    /// The dimensions of this item.
+    const DIMENSIONS: Dimensions;
+
     /// Create an object of this type from a byte slice.
     fn from_slice(slice: &[u8]) -> Self;
 }
@@ -131,42 +367,67 @@ pub struct ItemIter<'a, T: Item> {
 }
 
 impl Item for i8 {
+    const DATA_TYPE: DataType = DataType::I8;
+    const DIMENSIONS: Dimensions = Dimensions::Scalar;
+
     fn from_slice(slice: &[u8]) -> Self {
         slice[0] as i8
     }
 }
 
 impl Item for i16 {
+    const DATA_TYPE: DataType = DataType::I16;
+    const DIMENSIONS: Dimensions = Dimensions::Scalar;
+
     fn from_slice(slice: &[u8]) -> Self {
         LE::read_i16(slice)
     }
 }
 
 impl Item for u8 {
+    const DATA_TYPE: DataType = DataType::U8;
+    const DIMENSIONS: Dimensions = Dimensions::Scalar;
+
     fn from_slice(slice: &[u8]) -> Self {
         slice[0]
     }
 }
 
 impl Item for u16 {
+    const DATA_TYPE: DataType = DataType::U16;
+    const DIMENSIONS: Dimensions = Dimensions::Scalar;
+
     fn from_slice(slice: &[u8]) -> Self {
         LE::read_u16(slice)
     }
 }
 
 impl Item for u32 {
+    const DATA_TYPE: DataType = DataType::U32;
+    const DIMENSIONS: Dimensions = Dimensions::Scalar;
+
     fn from_slice(slice: &[u8]) -> Self {
         LE::read_u32(slice)
     }
 }
 
 impl Item for f32 {
+    const DATA_TYPE: DataType = DataType::F32;
+    const DIMENSIONS: Dimensions = Dimensions::Scalar;
+
     fn from_slice(slice: &[u8]) -> Self {
         LE::read_f32(slice)
     }
 }
 
 impl<T: Item> Item for [T; 2] {
+    const DATA_TYPE: DataType = T::DATA_TYPE;
+    const DIMENSIONS: Dimensions = match T::DIMENSIONS {
+        Dimensions::Scalar => Dimensions::Vec2,
+        Dimensions::Vec2 => Dimensions::Mat2,
+        _ => panic!("unsupported accessor item type"),
+    };
+
     fn from_slice(slice: &[u8]) -> Self {
         assert!(slice.len() >= 2 * mem::size_of::<T>());
         [T::from_slice(slice),
@@ -175,6 +436,13 @@ impl<T: Item> Item for [T; 2] {
 }
 
 impl<T: Item> Item for [T; 3] {
+    const DATA_TYPE: DataType = T::DATA_TYPE;
+    const DIMENSIONS: Dimensions = match T::DIMENSIONS {
+        Dimensions::Scalar => Dimensions::Vec3,
+        Dimensions::Vec3 => Dimensions::Mat3,
+        _ => panic!("unsupported accessor item type"),
+    };
+
     fn from_slice(slice: &[u8]) -> Self {
         assert!(slice.len() >= 3 * mem::size_of::<T>());
         [T::from_slice(slice),
@@ -184,6 +452,13 @@ impl<T: Item> Item for [T; 3] {
 }
 
 impl<T: Item> Item for [T; 4] {
+    const DATA_TYPE: DataType = T::DATA_TYPE;
+    const DIMENSIONS: Dimensions = match T::DIMENSIONS {
+        Dimensions::Scalar => Dimensions::Vec4,
+        Dimensions::Vec4 => Dimensions::Mat4,
+        _ => panic!("unsupported accessor item type"),
+    };
+
     fn from_slice(slice: &[u8]) -> Self {
         assert!(slice.len() >= 4 * mem::size_of::<T>());
         [T::from_slice(slice),
@@ -206,17 +481,24 @@ impl<'a, T: Item> ItemIter<'a, T> {
 
 impl<'a, 's, T: Item> Iter<'s, T> {
     /// Constructor.
+    ///
+    /// Returns `None` if the data type or dimensions of `T` do not match those
+    /// of the accessor, or if the buffer data is unavailable.
     pub fn new<F>(
         accessor: super::Accessor<'a>,
         get_buffer_data: F,
     ) -> Option<Iter<'s, T>>
         where F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
     {
+        if T::DATA_TYPE != accessor.data_type()
+            || T::DIMENSIONS != accessor.dimensions()
+            || mem::size_of::<T>() != accessor.size()
+        {
+            return None;
+        }
         let is_sparse = accessor.sparse().is_some();
         if is_sparse {
             let sparse = accessor.sparse();
-            let indices = sparse.as_ref().unwrap().indices();
-            let values = sparse.as_ref().unwrap().values();
             let base_iter = {
                 let view = accessor.view();
                 let stride = view.stride().unwrap_or(mem::size_of::<T>());
@@ -229,39 +511,9 @@ impl<'a, 's, T: Item> Iter<'s, T> {
                 };
                 ItemIter::new(subslice, stride)
             };
-            let sparse_count = sparse.as_ref().unwrap().count() as usize;
-            let index_iter = {
-                let view = indices.view();
-                let index_size = indices.index_type().size();
-                let stride = view.stride().unwrap_or(index_size);
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    let start = indices.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + index_size;
-                    &slice[start..end]
-                } else {
-                    return None
-                };
-                match indices.index_type() {
-                    accessor::sparse::IndexType::U8 => SparseIndicesIter::U8(ItemIter::new(subslice, stride)),
-                    accessor::sparse::IndexType::U16 => SparseIndicesIter::U16(ItemIter::new(subslice, stride)),
-                    accessor::sparse::IndexType::U32 => SparseIndicesIter::U32(ItemIter::new(subslice, stride)),
-                }
-            };
-            let value_iter = {
-                let view = values.view();
-                let stride = view.stride().unwrap_or(mem::size_of::<T>());
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    let start = values.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + mem::size_of::<T>();
-                    &slice[start..end]
-                } else {
-                    return None
-                };
-                ItemIter::new(subslice, stride)
-            };
+            let (index_iter, value_iter) = sparse_iters(sparse.as_ref().unwrap(), &get_buffer_data)?;
             Some(Iter::Sparse(SparseIter::new(base_iter, index_iter, value_iter)))
         } else {
-            debug_assert_eq!(mem::size_of::<T>(), accessor.size());
             debug_assert!(mem::size_of::<T>() > 0);
             let view = accessor.view();
             let stride = view.stride().unwrap_or(mem::size_of::<T>());
@@ -334,3 +586,172 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
         (hint, Some(hint))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{F32Iter, Iter, SparseOverrides};
+    use crate::Gltf;
+
+    #[test]
+    fn sparse_overrides() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "buffers": [ { "byteLength": 28 } ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 16 },
+                { "buffer": 0, "byteOffset": 16, "byteLength": 4 },
+                { "buffer": 0, "byteOffset": 20, "byteLength": 8 }
+            ],
+            "accessors": [
+                {
+                    "bufferView": 0,
+                    "componentType": 5126,
+                    "count": 4,
+                    "type": "SCALAR",
+                    "sparse": {
+                        "count": 2,
+                        "indices": { "bufferView": 1, "componentType": 5123 },
+                        "values": { "bufferView": 2 }
+                    }
+                }
+            ]
+        }"#).unwrap();
+        let mut data = vec![0u8; 16];
+        data.extend_from_slice(&[1, 0, 3, 0]);
+        data.extend_from_slice(&2.5f32.to_bits().to_le_bytes());
+        data.extend_from_slice(&(-1.0f32).to_bits().to_le_bytes());
+        let accessor = gltf.accessors().next().unwrap();
+        let overrides = SparseOverrides::<f32>::new(accessor, |_| Some(&data[..]))
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(overrides, vec![(1, 2.5), (3, -1.0)]);
+    }
+
+    #[test]
+    fn f32_iter_interleaved() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "buffers": [ { "byteLength": 30 } ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 24, "byteStride": 8 },
+                { "buffer": 0, "byteOffset": 24, "byteLength": 1 },
+                { "buffer": 0, "byteOffset": 28, "byteLength": 2 }
+            ],
+            "accessors": [
+                {
+                    "bufferView": 0,
+                    "componentType": 5121,
+                    "normalized": true,
+                    "count": 3,
+                    "type": "VEC2"
+                },
+                {
+                    "bufferView": 0,
+                    "byteOffset": 4,
+                    "componentType": 5122,
+                    "normalized": true,
+                    "count": 3,
+                    "type": "SCALAR"
+                },
+                {
+                    "bufferView": 0,
+                    "byteOffset": 6,
+                    "componentType": 5123,
+                    "count": 3,
+                    "type": "SCALAR",
+                    "sparse": {
+                        "count": 1,
+                        "indices": { "bufferView": 1, "componentType": 5121 },
+                        "values": { "bufferView": 2 }
+                    }
+                }
+            ]
+        }"#).unwrap();
+        let mut data = Vec::new();
+        for &(a, b, c, d) in &[(255u8, 0u8, -32767i16, 10u16), (0, 255, 32767, 20), (0, 0, 0, 30)] {
+            data.extend_from_slice(&[a, b, 0, 0]);
+            data.extend_from_slice(&c.to_le_bytes());
+            data.extend_from_slice(&d.to_le_bytes());
+        }
+        data.extend_from_slice(&[1, 0, 0, 0]);
+        data.extend_from_slice(&7u16.to_le_bytes());
+
+        let read = |index: usize| {
+            let accessor = gltf.accessors().nth(index).unwrap();
+            F32Iter::new(accessor, |_| Some(&data[..])).unwrap().collect::<Vec<_>>()
+        };
+        assert_eq!(read(0), vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![0.0, 0.0]]);
+        let signed = read(1);
+        assert_eq!(signed.len(), 3);
+        for (value, expected) in signed.iter().zip(&[-1.0, 1.0, 0.0]) {
+            assert!((value[0] - expected).abs() < 1e-6);
+        }
+        assert_eq!(read(2), vec![vec![10.0], vec![7.0], vec![30.0]]);
+    }
+
+    #[test]
+    fn iter_element_size() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "buffers": [ { "byteLength": 40 } ],
+            "bufferViews": [ { "buffer": 0, "byteLength": 40, "byteStride": 20 } ],
+            "accessors": [
+                { "bufferView": 0, "byteOffset": 8, "componentType": 5126, "count": 2, "type": "VEC3" }
+            ]
+        }"#).unwrap();
+        let data = (0..10).flat_map(|x| (x as f32).to_le_bytes().to_vec()).collect::<Vec<_>>();
+        let accessor = gltf.accessors().next().unwrap();
+
+        let iter = Iter::<[f32; 3]>::new(accessor.clone(), |_| Some(&data[..])).unwrap();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), [[2.0, 3.0, 4.0], [7.0, 8.0, 9.0]]);
+
+        assert!(Iter::<[f32; 2]>::new(accessor.clone(), |_| Some(&data[..])).is_none());
+        assert!(Iter::<f32>::new(accessor, |_| Some(&data[..])).is_none());
+    }
+
+    #[test]
+    fn sparse_applied() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "buffers": [ { "byteLength": 32 } ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 20 },
+                { "buffer": 0, "byteOffset": 20, "byteLength": 2 },
+                { "buffer": 0, "byteOffset": 24, "byteLength": 8 }
+            ],
+            "accessors": [
+                {
+                    "bufferView": 0,
+                    "componentType": 5126,
+                    "count": 5,
+                    "type": "SCALAR",
+                    "sparse": {
+                        "count": 2,
+                        "indices": { "bufferView": 1, "componentType": 5121 },
+                        "values": { "bufferView": 2 }
+                    }
+                }
+            ]
+        }"#).unwrap();
+        let mut data = Vec::new();
+        for &x in &[0.0f32, 1.0, 2.0, 3.0, 4.0] {
+            data.extend_from_slice(&x.to_le_bytes());
+        }
+        data.extend_from_slice(&[1, 3, 0, 0]);
+        data.extend_from_slice(&10.0f32.to_le_bytes());
+        data.extend_from_slice(&30.0f32.to_le_bytes());
+        let accessor = gltf.accessors().next().unwrap();
+
+        let values = Iter::<f32>::new(accessor.clone(), |_| Some(&data[..]))
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(values, [0.0, 10.0, 2.0, 30.0, 4.0]);
+
+        let values = F32Iter::new(accessor, |_| Some(&data[..]))
+            .unwrap()
+            .map(|x| x[0])
+            .collect::<Vec<_>>();
+        assert_eq!(values, [0.0, 10.0, 2.0, 30.0, 4.0]);
+    }
+}
@@ -95,7 +95,7 @@ impl<'a> Animation<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// Returns an `Iterator` over the animation samplers.
@@ -58,6 +58,22 @@ pub enum MorphTargetWeights<'a> {
     F32(accessor::Iter<'a, f32>),
 }
 
+/// An animated property value sampled at a point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// XYZ translation.
+    Translation([f32; 3]),
+
+    /// XYZW rotation quaternion.
+    Rotation([f32; 4]),
+
+    /// XYZ scale.
+    Scale([f32; 3]),
+
+    /// Morph target weights.
+    MorphTargetWeights(Vec<f32>),
+}
+
 /// Animation output sampler values.
 pub enum ReadOutputs<'a> {
     /// XYZ translations of type `[f32; 3]`.
@@ -174,4 +190,272 @@ where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
             },
         }
     }
+
+    /// Decodes the keyframes of the channel for sampling.
+    ///
+    /// Returns `None` if the sampler data cannot be read, the number of
+    /// outputs does not match the number of inputs, or the interpolation is
+    /// `CATMULLROMSPLINE`.
+    pub fn read_keyframes(&self) -> Option<Keyframes> {
+        use crate::animation::{Interpolation, Property};
+
+        let property = self.channel.target().property();
+        let interpolation = self.channel.sampler().interpolation();
+        let elements = match interpolation {
+            // Cubic spline keyframes store an in-tangent, a value, and an out-tangent.
+            Interpolation::CubicSpline => 3,
+            Interpolation::CatmullRomSpline => return None,
+            Interpolation::Linear | Interpolation::Step => 1,
+        };
+
+        let times = self.read_inputs()?.collect::<Vec<_>>();
+        let outputs = match self.read_outputs()? {
+            ReadOutputs::Translations(iter) | ReadOutputs::Scales(iter) => {
+                iter.flat_map(|x| x.to_vec()).collect::<Vec<_>>()
+            },
+            ReadOutputs::Rotations(iter) => iter.into_f32().flat_map(|x| x.to_vec()).collect(),
+            ReadOutputs::MorphTargetWeights(iter) => iter.into_f32().collect(),
+        };
+
+        let count = times.len() * elements;
+        if count == 0 || outputs.is_empty() || outputs.len() % count != 0 {
+            return None;
+        }
+        let width = outputs.len() / count;
+        let expected_width = match property {
+            Property::Translation | Property::Scale => 3,
+            Property::Rotation => 4,
+            Property::MorphTargetWeights => width,
+        };
+        if width != expected_width {
+            return None;
+        }
+
+        Some(Keyframes { property, interpolation, times, outputs, elements, width })
+    }
+}
+
+/// The decoded keyframes of an animation channel.
+///
+/// Returned by [`Reader::read_keyframes`], so that a channel can be sampled
+/// repeatedly without reading its accessors again.
+///
+/// [`Reader::read_keyframes`]: struct.Reader.html#method.read_keyframes
+#[derive(Clone, Debug)]
+pub struct Keyframes {
+    property: crate::animation::Property,
+    interpolation: crate::animation::Interpolation,
+    times: Vec<f32>,
+    outputs: Vec<f32>,
+    elements: usize,
+    width: usize,
+}
+
+impl Keyframes {
+    /// Samples the channel at time `t`, in seconds.
+    ///
+    /// `t` is clamped to the range of the input keyframes. The output is
+    /// interpolated according to the sampler: `STEP` holds the previous
+    /// keyframe, `LINEAR` interpolates linearly (spherically for rotations)
+    /// and `CUBICSPLINE` evaluates the Hermite spline through the keyframes.
+    pub fn sample(&self, t: f32) -> Value {
+        use std::cmp::Ordering;
+        use crate::animation::{Interpolation, Property};
+
+        let (times, elements, width) = (&self.times, self.elements, self.width);
+        let element = |keyframe: usize, offset: usize| {
+            let start = (keyframe * elements + offset) * width;
+            &self.outputs[start..start + width]
+        };
+        let value = |keyframe: usize| element(keyframe, elements / 2);
+
+        let last = times.len() - 1;
+        // Clamping also maps NaN to the first keyframe.
+        let t = t.max(times[0]).min(times[last]);
+        let sample = if t >= times[last] {
+            value(last).to_vec()
+        } else {
+            // Input times are strictly increasing, so the keyframe is the
+            // last one that starts at or before `t`.
+            let keyframe = match times.binary_search_by(|x| x.partial_cmp(&t).unwrap_or(Ordering::Less)) {
+                Ok(keyframe) => keyframe,
+                Err(next) => next - 1,
+            };
+            let (t0, t1) = (times[keyframe], times[keyframe + 1]);
+            let dt = t1 - t0;
+            let s = if dt > 0.0 { (t - t0) / dt } else { 0.0 };
+            let (from, to) = (value(keyframe), value(keyframe + 1));
+            match self.interpolation {
+                Interpolation::Step => from.to_vec(),
+                Interpolation::Linear if self.property == Property::Rotation => slerp(from, to, s),
+                Interpolation::Linear => {
+                    from.iter().zip(to).map(|(a, b)| a + (b - a) * s).collect()
+                },
+                Interpolation::CubicSpline => {
+                    let out_tangent = element(keyframe, 2);
+                    let in_tangent = element(keyframe + 1, 0);
+                    let (s2, s3) = (s * s, s * s * s);
+                    (0..width)
+                        .map(|i| {
+                            (2.0 * s3 - 3.0 * s2 + 1.0) * from[i]
+                                + (s3 - 2.0 * s2 + s) * dt * out_tangent[i]
+                                + (-2.0 * s3 + 3.0 * s2) * to[i]
+                                + (s3 - s2) * dt * in_tangent[i]
+                        })
+                        .collect()
+                },
+                // Rejected by `Reader::read_keyframes`.
+                Interpolation::CatmullRomSpline => unreachable!(),
+            }
+        };
+
+        match self.property {
+            Property::Translation => Value::Translation([sample[0], sample[1], sample[2]]),
+            Property::Rotation => Value::Rotation(normalize([sample[0], sample[1], sample[2], sample[3]])),
+            Property::Scale => Value::Scale([sample[0], sample[1], sample[2]]),
+            Property::MorphTargetWeights => Value::MorphTargetWeights(sample),
+        }
+    }
+}
+
+/// Scales a quaternion to unit length.
+fn normalize(q: [f32; 4]) -> [f32; 4] {
+    let length = q.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if length > 0.0 {
+        [q[0] / length, q[1] / length, q[2] / length, q[3] / length]
+    } else {
+        q
+    }
+}
+
+/// Spherically interpolates between two unit quaternions along the shortest
+/// path.
+fn slerp(a: &[f32], b: &[f32], s: f32) -> Vec<f32> {
+    let mut dot = a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>();
+    let sign = if dot < 0.0 { -1.0 } else { 1.0 };
+    dot *= sign;
+    let (wa, wb) = if dot > 0.9995 {
+        // Nearly parallel; fall back to linear interpolation.
+        (1.0 - s, s)
+    } else {
+        let theta = dot.acos();
+        let sin = theta.sin();
+        (((1.0 - s) * theta).sin() / sin, (s * theta).sin() / sin)
+    };
+    a.iter().zip(b).map(|(x, y)| wa * x + wb * sign * y).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+    use crate::{buffer, Gltf};
+
+    fn animated() -> (Gltf, Vec<buffer::Data>) {
+        use std::f32::consts::FRAC_1_SQRT_2;
+
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "nodes": [ {} ],
+            "buffers": [ { "byteLength": 204 } ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 12 },
+                { "buffer": 0, "byteOffset": 12, "byteLength": 36 },
+                { "buffer": 0, "byteOffset": 48, "byteLength": 48 },
+                { "buffer": 0, "byteOffset": 96, "byteLength": 108 }
+            ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR" },
+                { "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3" },
+                { "bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC4" },
+                { "bufferView": 3, "componentType": 5126, "count": 9, "type": "VEC3" }
+            ],
+            "animations": [
+                {
+                    "channels": [
+                        { "sampler": 0, "target": { "node": 0, "path": "translation" } },
+                        { "sampler": 1, "target": { "node": 0, "path": "translation" } },
+                        { "sampler": 2, "target": { "node": 0, "path": "rotation" } },
+                        { "sampler": 3, "target": { "node": 0, "path": "scale" } }
+                    ],
+                    "samplers": [
+                        { "input": 0, "output": 1, "interpolation": "STEP" },
+                        { "input": 0, "output": 1 },
+                        { "input": 0, "output": 2 },
+                        { "input": 0, "output": 3, "interpolation": "CUBICSPLINE" }
+                    ]
+                }
+            ]
+        }"#;
+        let mut floats = vec![0.0, 1.0, 2.0];
+        floats.extend(&[0.0, 0.0, 0.0, 2.0, 4.0, 0.0, 4.0, 4.0, 0.0]);
+        floats.extend(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0, 0.0, 1.0, 0.0]);
+        for &scale in &[1.0, 3.0, 3.0] {
+            // Zero in-tangent, value, zero out-tangent.
+            floats.extend(&[0.0, 0.0, 0.0, scale, scale, scale, 0.0, 0.0, 0.0]);
+        }
+        let mut data = Vec::new();
+        for f in floats {
+            data.extend_from_slice(&f32::to_bits(f).to_le_bytes());
+        }
+        (Gltf::from_slice(json.as_bytes()).unwrap(), vec![buffer::Data(data)])
+    }
+
+    fn sample(gltf: &Gltf, buffers: &[buffer::Data], channel: usize, t: f32) -> Value {
+        let animation = gltf.animations().next().unwrap();
+        let channel = animation.channels().nth(channel).unwrap();
+        let reader = channel.reader(|buffer| buffers.get(buffer.index()).map(|data| &data[..]));
+        reader.read_keyframes().unwrap().sample(t)
+    }
+
+    #[test]
+    fn sample_step() {
+        let (gltf, buffers) = animated();
+        assert_eq!(sample(&gltf, &buffers, 0, 0.5), Value::Translation([0.0, 0.0, 0.0]));
+        assert_eq!(sample(&gltf, &buffers, 0, 1.0), Value::Translation([2.0, 4.0, 0.0]));
+        assert_eq!(sample(&gltf, &buffers, 0, 1.9), Value::Translation([2.0, 4.0, 0.0]));
+    }
+
+    #[test]
+    fn sample_linear() {
+        let (gltf, buffers) = animated();
+        assert_eq!(sample(&gltf, &buffers, 1, 0.5), Value::Translation([1.0, 2.0, 0.0]));
+        assert_eq!(sample(&gltf, &buffers, 1, 1.25), Value::Translation([2.5, 4.0, 0.0]));
+
+        // Time is clamped to the keyframes.
+        assert_eq!(sample(&gltf, &buffers, 1, -1.0), Value::Translation([0.0, 0.0, 0.0]));
+        assert_eq!(sample(&gltf, &buffers, 1, 5.0), Value::Translation([4.0, 4.0, 0.0]));
+    }
+
+    #[test]
+    fn sample_linear_rotation() {
+        use std::f32::consts::PI;
+
+        let (gltf, buffers) = animated();
+
+        // The keyframes turn about Z by a quarter and then a half turn, and
+        // slerp moves between them at a constant angular speed.
+        for &(t, angle) in &[(0.5, PI / 4.0), (1.5, 3.0 * PI / 4.0), (1.75, 7.0 * PI / 8.0)] {
+            match sample(&gltf, &buffers, 2, t) {
+                Value::Rotation(r) => {
+                    assert_relative_eq!(r[0], 0.0);
+                    assert_relative_eq!(r[1], 0.0);
+                    assert_relative_eq!(r[2], (angle / 2.0).sin(), epsilon = 1e-5);
+                    assert_relative_eq!(r[3], (angle / 2.0).cos(), epsilon = 1e-5);
+                },
+                other => panic!("expected rotation, found {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn sample_cubic_spline() {
+        let (gltf, buffers) = animated();
+        assert_eq!(sample(&gltf, &buffers, 3, 0.0), Value::Scale([1.0, 1.0, 1.0]));
+        assert_eq!(sample(&gltf, &buffers, 3, 0.5), Value::Scale([2.0, 2.0, 2.0]));
+        match sample(&gltf, &buffers, 3, 0.25) {
+            Value::Scale(s) => assert_relative_eq!(s[0], 1.0 + 2.0 * 0.15625, epsilon = 1e-5),
+            other => panic!("expected scale, found {:?}", other),
+        }
+        assert_eq!(sample(&gltf, &buffers, 3, 1.5), Value::Scale([3.0, 3.0, 3.0]));
+    }
 }
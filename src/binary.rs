@@ -237,7 +237,12 @@ impl<'a> Glb<'a> {
     pub fn from_slice(mut data: &'a [u8]) -> Result<Self, crate::Error> {
         let header = Header::from_reader(&mut data)
             .and_then(|header| {
-                let contents_length = header.length as usize - Header::size_of();
+                let contents_length = (header.length as usize)
+                    .checked_sub(Header::size_of())
+                    .ok_or(Error::Length {
+                        length: header.length,
+                        length_read: data.len() + Header::size_of(),
+                    })?;
                 if contents_length <= data.len() {
                     Ok(header)
                 } else {
@@ -266,7 +271,12 @@ impl<'a> Glb<'a> {
         let header = Header::from_reader(&mut reader).map_err(crate::Error::Binary)?;
         match header.version {
             2 => {
-                let glb_len = header.length - Header::size_of() as u32;
+                let glb_len = header.length
+                    .checked_sub(Header::size_of() as u32)
+                    .ok_or(crate::Error::Binary(Error::Length {
+                        length: header.length,
+                        length_read: Header::size_of(),
+                    }))?;
                 let mut buf = vec![0; glb_len as usize];
                 if let Err(e) = reader.read_exact(&mut buf).map_err(Error::Io) {
                     Err(crate::Error::Binary(e))
@@ -287,8 +297,26 @@ impl<'a> Glb<'a> {
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use std::error::Error;
-        write!(f, "{}", self.description())
+        match *self {
+            Error::Io(ref e) => write!(f, "{}", e),
+            Error::Version(version) => write!(f, "unsupported version {}", version),
+            Error::Magic(magic) => write!(f, "not glTF magic: {:?}", magic),
+            Error::Length { length, length_read } => write!(
+                f,
+                "could not completely read the object: header declares {} bytes but {} are available",
+                length,
+                length_read,
+            ),
+            Error::ChunkLength { ty, length, length_read } => write!(
+                f,
+                "{:?} chunk length {} exceeds the {} bytes remaining",
+                ty,
+                length,
+                length_read,
+            ),
+            Error::ChunkType(ty) => write!(f, "was not expecting {:?} chunk", ty),
+            Error::UnknownChunkType(ty) => write!(f, "unknown chunk type: {:?}", ty),
+        }
     }
 }
 
@@ -310,4 +338,141 @@ impl ::std::error::Error for Error {
              Error::UnknownChunkType(_) => "unknown chunk type",
         }
     }
+
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        match *self {
+            Error::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChunkType, Error, Glb};
+    use crate::Error::Binary;
+
+    fn glb(version: u32, length: u32, chunks: &[u8]) -> Vec<u8> {
+        let mut data = b"glTF".to_vec();
+        data.extend_from_slice(&version.to_le_bytes());
+        data.extend_from_slice(&length.to_le_bytes());
+        data.extend_from_slice(chunks);
+        data
+    }
+
+    fn chunk(ty: &[u8; 4], length: u32, contents: &[u8]) -> Vec<u8> {
+        let mut data = length.to_le_bytes().to_vec();
+        data.extend_from_slice(ty);
+        data.extend_from_slice(contents);
+        data
+    }
+
+    #[test]
+    fn split_chunks() {
+        let mut chunks = chunk(b"JSON", 4, b"{}  ");
+        chunks.extend(chunk(b"BIN\0", 4, &[1, 2, 3, 4]));
+        let data = glb(2, 12 + chunks.len() as u32, &chunks);
+        let glb = Glb::from_slice(&data).unwrap();
+        assert_eq!(&*glb.json, b"{}  ");
+        assert_eq!(glb.bin.as_ref().map(|x| &**x), Some(&[1, 2, 3, 4][..]));
+        assert_eq!(glb.to_vec().unwrap(), data);
+    }
+
+    #[test]
+    fn pad_chunks() {
+        use std::borrow::Cow;
+
+        let contents = Glb {
+            header: super::Header { magic: *b"glTF", version: 2, length: 0 },
+            json: Cow::Borrowed(b"{}"),
+            bin: Some(Cow::Borrowed(&[1, 2, 3, 4, 5])),
+        };
+        let data = contents.to_vec().unwrap();
+
+        // The JSON chunk is padded with spaces and the BIN chunk with zeros.
+        let mut chunks = chunk(b"JSON", 4, b"{}  ");
+        chunks.extend(chunk(b"BIN\0", 8, &[1, 2, 3, 4, 5, 0, 0, 0]));
+        assert_eq!(data, glb(2, 12 + chunks.len() as u32, &chunks));
+
+        let read = Glb::from_slice(&data).unwrap();
+        assert_eq!(&*read.json, b"{}  ");
+        assert_eq!(read.bin.as_ref().map(|x| &**x), Some(&[1, 2, 3, 4, 5, 0, 0, 0][..]));
+    }
+
+    #[test]
+    fn reject_magic() {
+        let mut data = glb(2, 12, &[]);
+        data[0] = b'g';
+        data[1] = b'l';
+        data[2] = b'b';
+        match Glb::from_slice(&data) {
+            Err(Binary(Error::Magic(magic))) => assert_eq!(&magic, b"glbF"),
+            other => panic!("expected magic error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reject_version() {
+        match Glb::from_slice(&glb(1, 12, &[])) {
+            Err(Binary(Error::Version(1))) => {},
+            other => panic!("expected version error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reject_truncated_file() {
+        let chunks = chunk(b"JSON", 4, b"{}  ");
+        let data = glb(2, 12 + chunks.len() as u32 + 100, &chunks);
+        match Glb::from_slice(&data) {
+            Err(Binary(Error::Length { .. })) => {},
+            other => panic!("expected length error, found {:?}", other),
+        }
+        match Glb::from_reader(&data[..]) {
+            Err(Binary(Error::Io(_))) => {},
+            other => panic!("expected I/O error, found {:?}", other),
+        }
+        match Glb::from_slice(&data[..6]) {
+            Err(Binary(Error::Io(_))) => {},
+            other => panic!("expected I/O error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reject_header_length_underflow() {
+        match Glb::from_slice(&glb(2, 4, &[])) {
+            Err(Binary(Error::Length { length: 4, .. })) => {},
+            other => panic!("expected length error, found {:?}", other),
+        }
+        match Glb::from_reader(&glb(2, 4, &[])[..]) {
+            Err(Binary(Error::Length { length: 4, .. })) => {},
+            other => panic!("expected length error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reject_chunk_length_overflow() {
+        let chunks = chunk(b"JSON", 64, b"{}  ");
+        let data = glb(2, 12 + chunks.len() as u32, &chunks);
+        match Glb::from_slice(&data) {
+            Err(Binary(Error::ChunkLength { ty: ChunkType::Json, length: 64, length_read: 4 })) => {},
+            other => panic!("expected chunk length error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn error_messages() {
+        let messages = [
+            Error::Version(1).to_string(),
+            Error::Magic(*b"glbF").to_string(),
+            Error::Length { length: 100, length_read: 28 }.to_string(),
+            Error::ChunkLength { ty: ChunkType::Bin, length: 64, length_read: 4 }.to_string(),
+        ];
+        assert_eq!(messages[0], "unsupported version 1");
+        assert_eq!(messages[1], "not glTF magic: [103, 108, 98, 70]");
+        assert_eq!(
+            messages[2],
+            "could not completely read the object: header declares 100 bytes but 28 are available",
+        );
+        assert_eq!(messages[3], "Bin chunk length 64 exceeds the 4 bytes remaining");
+    }
 }
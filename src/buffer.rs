@@ -76,6 +76,28 @@ impl<'a> Buffer<'a> {
         self.index
     }
 
+    /// Decodes the buffer data embedded in a `data:` URI.
+    ///
+    /// Returns `Error::NotDataUri` if the buffer data is stored elsewhere,
+    /// either in an external file or in the `BIN` section of binary glTF,
+    /// and `Error::BufferLength` if the decoded data is shorter than
+    /// `byteLength`.
+    #[cfg(feature = "import_data_reference")]
+    pub fn decode_uri(&self) -> crate::Result<Vec<u8>> {
+        let data = match self.json.uri.as_ref() {
+            Some(uri) => crate::import::decode_data_uri(uri)?,
+            None => return Err(crate::Error::NotDataUri),
+        };
+        if data.len() < self.length() {
+            return Err(crate::Error::BufferLength {
+                buffer: self.index,
+                expected: self.length(),
+                actual: data.len(),
+            });
+        }
+        Ok(data)
+    }
+
     /// Returns the buffer data source.
     pub fn source(&self) -> Source<'a> {
         if let Some(uri) = self.json.uri.as_ref().map(String::as_str) {
@@ -93,7 +115,7 @@ impl<'a> Buffer<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// Optional application specific data.
@@ -152,10 +174,30 @@ impl<'a> View<'a> {
             })
     }
 
+    /// Splits the contents of the buffer view into elements of
+    /// `element_size` bytes each, honouring the byte stride.
+    ///
+    /// `data` must be the contents of this view, i.e. the parent buffer data
+    /// sliced by `offset()` and `length()`. Without a stride, elements are
+    /// assumed to be tightly packed. Only whole elements are visited.
+    pub fn strided_chunks<'s>(
+        &self,
+        data: &'s [u8],
+        element_size: usize,
+    ) -> impl Iterator<Item = &'s [u8]> + Clone {
+        let stride = self.stride().unwrap_or(element_size);
+        let count = if element_size == 0 || data.len() < element_size {
+            0
+        } else {
+            (data.len() - element_size) / stride + 1
+        };
+        (0..count).map(move |i| &data[i * stride..i * stride + element_size])
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// Optional target the buffer should be bound to.
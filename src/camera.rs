@@ -62,7 +62,7 @@ impl<'a> Camera<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// Returns the camera's projection.
@@ -79,6 +79,17 @@ impl<'a> Camera<'a> {
         }
     }
 
+    /// Returns the column-major projection matrix of the camera.
+    ///
+    /// `aspect_ratio` is the aspect ratio of the viewport, which is only used
+    /// by perspective projections that do not specify their own.
+    pub fn projection_matrix(&self, aspect_ratio: f32) -> [[f32; 4]; 4] {
+        match self.projection() {
+            Projection::Orthographic(orthographic) => orthographic.projection_matrix(),
+            Projection::Perspective(perspective) => perspective.projection_matrix(aspect_ratio),
+        }
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
@@ -114,6 +125,17 @@ impl<'a> Orthographic<'a> {
         self.json.znear
     }
 
+    /// Returns the column-major orthographic projection matrix.
+    pub fn projection_matrix(&self) -> [[f32; 4]; 4] {
+        let (n, f) = (self.json.znear, self.json.zfar);
+        [
+            [1.0 / self.json.xmag, 0.0, 0.0, 0.0],
+            [0.0, 1.0 / self.json.ymag, 0.0, 0.0],
+            [0.0, 0.0, 2.0 / (n - f), 0.0],
+            [0.0, 0.0, (f + n) / (n - f), 1.0],
+        ]
+    }
+
     ///  Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
@@ -149,8 +171,136 @@ impl<'a> Perspective<'a> {
         self.json.znear
     }
 
+    /// Returns the column-major perspective projection matrix.
+    ///
+    /// The camera's own aspect ratio takes precedence over `aspect_ratio`,
+    /// the aspect ratio of the viewport. An infinite projection is returned
+    /// when the far clipping plane is not specified.
+    pub fn projection_matrix(&self, aspect_ratio: f32) -> [[f32; 4]; 4] {
+        let a = self.json.aspect_ratio.unwrap_or(aspect_ratio);
+        let t = (0.5 * self.json.yfov).tan();
+        let n = self.json.znear;
+        let (m22, m32) = match self.json.zfar {
+            Some(f) => ((f + n) / (n - f), 2.0 * f * n / (n - f)),
+            None => (-1.0, -2.0 * n),
+        };
+        [
+            [1.0 / (a * t), 0.0, 0.0, 0.0],
+            [0.0, 1.0 / t, 0.0, 0.0],
+            [0.0, 0.0, m22, -1.0],
+            [0.0, 0.0, m32, 0.0],
+        ]
+    }
+
     ///  Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Projection;
+    use crate::{Error, Gltf};
+
+    #[test]
+    fn projection() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "cameras": [
+                { "type": "orthographic", "orthographic": { "xmag": 2.0, "ymag": 1.0, "zfar": 10.0, "znear": 0.5 } },
+                { "type": "perspective", "perspective": { "yfov": 0.75, "znear": 0.1 } }
+            ]
+        }"#).unwrap();
+        let cameras = gltf.cameras().collect::<Vec<_>>();
+
+        match cameras[0].projection() {
+            Projection::Orthographic(orthographic) => {
+                assert_eq!(orthographic.xmag(), 2.0);
+                assert_eq!(orthographic.ymag(), 1.0);
+                assert_eq!(orthographic.zfar(), 10.0);
+                assert_eq!(orthographic.znear(), 0.5);
+            },
+            other => panic!("expected orthographic projection, found {:?}", other),
+        }
+        match cameras[1].projection() {
+            Projection::Perspective(perspective) => {
+                assert_eq!(perspective.aspect_ratio(), None);
+                assert_eq!(perspective.yfov(), 0.75);
+                assert_eq!(perspective.zfar(), None);
+                assert_eq!(perspective.znear(), 0.1);
+            },
+            other => panic!("expected perspective projection, found {:?}", other),
+        }
+
+        // A camera whose type names an absent projection is rejected at load.
+        let result = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "cameras": [ { "type": "perspective", "orthographic": { "xmag": 1.0, "ymag": 1.0, "zfar": 1.0, "znear": 0.0 } } ]
+        }"#);
+        match result {
+            Err(Error::Validation(errors)) => assert_eq!(errors.len(), 1),
+            other => panic!("expected validation error, found {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn projection_matrix() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "cameras": [
+                { "type": "perspective", "perspective": { "yfov": 1.5707964, "znear": 1.0, "zfar": 3.0 } },
+                { "type": "orthographic", "orthographic": { "xmag": 1.0, "ymag": 1.0, "zfar": 1.0, "znear": -1.0 } }
+            ]
+        }"#).unwrap();
+        let cameras = gltf.cameras().collect::<Vec<_>>();
+
+        // A 90 degree field of view at the viewport aspect ratio of 2.
+        let expected = [
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -2.0, -1.0],
+            [0.0, 0.0, -3.0, 0.0],
+        ];
+        let matrix = cameras[0].projection_matrix(2.0);
+        for (column, expected) in matrix.iter().zip(expected.iter()) {
+            for (x, y) in column.iter().zip(expected.iter()) {
+                assert_relative_eq!(x, y, epsilon = 1.0e-6);
+            }
+        }
+
+        // The unit orthographic projection flips the z axis.
+        assert_eq!(cameras[1].projection_matrix(2.0), [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    #[test]
+    fn projection_matrix_infinite() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "cameras": [
+                { "type": "perspective", "perspective": { "aspectRatio": 0.5, "yfov": 1.5707964, "znear": 0.5 } }
+            ]
+        }"#).unwrap();
+        let camera = gltf.cameras().next().unwrap();
+
+        // Without zfar the far plane is at infinity, and the camera's own
+        // aspect ratio takes precedence over the viewport's.
+        let expected = [
+            [2.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -1.0, -1.0],
+            [0.0, 0.0, -1.0, 0.0],
+        ];
+        let matrix = camera.projection_matrix(2.0);
+        for (column, expected) in matrix.iter().zip(expected.iter()) {
+            for (x, y) in column.iter().zip(expected.iter()) {
+                assert_relative_eq!(x, y, epsilon = 1.0e-6);
+            }
+        }
+    }
+}
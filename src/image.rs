@@ -100,7 +100,19 @@ impl<'a> Image<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
+    }
+
+    /// Decodes the encoded image data embedded in a `data:` URI.
+    ///
+    /// Returns `Error::NotDataUri` if the image data is stored elsewhere,
+    /// either in an external file or in a buffer view.
+    #[cfg(feature = "import_data_reference")]
+    pub fn decode_uri(&self) -> crate::Result<Vec<u8>> {
+        match self.json.uri.as_ref() {
+            Some(uri) => crate::import::decode_data_uri(uri),
+            None => Err(crate::Error::NotDataUri),
+        }
     }
 
     /// Returns the image data source.
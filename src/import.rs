@@ -10,11 +10,48 @@ use crate::{Document, Error, Gltf, Result};
 /// Return type of `import`.
 type Import = (Document, Vec<buffer::Data>, Vec<image::Data>);
 
+/// Options for [`import_with_options`] and [`import_slice_with_options`].
+///
+/// [`import_with_options`]: fn.import_with_options.html
+/// [`import_slice_with_options`]: fn.import_slice_with_options.html
+#[derive(Clone, Debug, Default)]
+pub struct ImportOptions {
+    /// Whether objects with identical names share the storage of a single
+    /// name, as with [`json::Root::intern_names`].
+    ///
+    /// [`json::Root::intern_names`]: json/struct.Root.html#method.intern_names
+    #[cfg(feature = "intern_names")]
+    pub intern_names: bool,
+}
+
+impl ImportOptions {
+    /// Applies the options to a loaded document.
+    #[cfg(feature = "intern_names")]
+    fn apply(&self, document: &mut Document) {
+        if self.intern_names {
+            document.0.intern_names();
+        }
+    }
+
+    /// Applies the options to a loaded document.
+    #[cfg(not(feature = "intern_names"))]
+    fn apply(&self, _: &mut Document) {}
+}
+
 /// Represents the set of URI schemes the importer supports.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 enum Scheme<'a> {
-    /// `data:[<media type>];base64,<data>`.
-    Data(Option<&'a str>, &'a str),
+    /// `data:[<media type>][;base64],<data>`.
+    Data {
+        /// The media type, if provided.
+        mime_type: Option<&'a str>,
+
+        /// Whether the data is base 64 encoded rather than percent-encoded.
+        base64: bool,
+
+        /// The encoded data.
+        data: &'a str,
+    },
 
     /// `file:[//]<absolute file path>`.
     ///
@@ -32,14 +69,19 @@ impl<'a> Scheme<'a> {
     fn parse<'s>(uri: &'s str) -> Scheme<'s> {
         if uri.contains(":") {
             if uri.starts_with("data:") {
-                let match0 = &uri["data:".len()..].split(";base64,").nth(0);
-                let match1 = &uri["data:".len()..].split(";base64,").nth(1);
-                if match1.is_some() {
-                    Scheme::Data(Some(match0.unwrap()), match1.unwrap())
-                } else if match0.is_some() {
-                    Scheme::Data(None, match0.unwrap())
-                } else {
-                    Scheme::Unsupported
+                let mut split = uri["data:".len()..].splitn(2, ',');
+                match (split.next(), split.next()) {
+                    (Some(header), Some(data)) => {
+                        let base64 = header.ends_with(";base64");
+                        let mime_type = if base64 {
+                            &header[..header.len() - ";base64".len()]
+                        } else {
+                            header
+                        };
+                        let mime_type = mime_type.split(';').next().filter(|x| !x.is_empty());
+                        Scheme::Data { mime_type, base64, data }
+                    },
+                    _ => Scheme::Unsupported,
                 }
             } else if uri.starts_with("file://") {
                 Scheme::File(&uri["file://".len()..])
@@ -55,14 +97,70 @@ impl<'a> Scheme<'a> {
 
     fn read(base: &Path, uri: &str) -> Result<Vec<u8>> {
         match Scheme::parse(uri) {
-            Scheme::Data(_, base64) => base64::decode(&base64).map_err(Error::Base64),
-            Scheme::File(path) => read_to_end(path),
-            Scheme::Relative => read_to_end(base.join(uri)),
+            Scheme::Data { base64, data, .. } => decode_data(base64, data),
+            Scheme::File(path) => read_to_end(percent_decode(path)),
+            Scheme::Relative => read_to_end(base.join(percent_decode(uri))),
             Scheme::Unsupported => Err(Error::UnsupportedScheme),
         }
     }
 }
 
+/// Decodes the payload of a `data:` URI.
+fn decode_data(base64: bool, data: &str) -> Result<Vec<u8>> {
+    if base64 {
+        base64::decode(data).map_err(Error::Base64)
+    } else {
+        Ok(percent_decode_bytes(data))
+    }
+}
+
+/// Decodes the data embedded in a `data:` URI.
+///
+/// Returns `Error::NotDataUri` if the URI does not use the `data:` scheme, in
+/// which case the data must be loaded from elsewhere.
+pub(crate) fn decode_data_uri(uri: &str) -> Result<Vec<u8>> {
+    match Scheme::parse(uri) {
+        Scheme::Data { base64, data, .. } => decode_data(base64, data),
+        _ => Err(Error::NotDataUri),
+    }
+}
+
+/// Decodes `%XX` escape sequences in a URI path.
+///
+/// Malformed escape sequences are left as they are.
+fn percent_decode(uri: &str) -> String {
+    String::from_utf8(percent_decode_bytes(uri)).unwrap_or_else(|_| uri.to_string())
+}
+
+/// Decodes `%XX` escape sequences into raw bytes.
+///
+/// Malformed escape sequences are left as they are.
+fn percent_decode_bytes(uri: &str) -> Vec<u8> {
+    fn hex(byte: u8) -> Option<u8> {
+        match byte {
+            b'0'..=b'9' => Some(byte - b'0'),
+            b'a'..=b'f' => Some(byte - b'a' + 10),
+            b'A'..=b'F' => Some(byte - b'A' + 10),
+            _ => None,
+        }
+    }
+    let bytes = uri.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(hi), Some(lo)) = (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                decoded.push(hi << 4 | lo);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    decoded
+}
+
 fn read_to_end<P>(path: P) -> Result<Vec<u8>>
 where P: AsRef<Path>
 {
@@ -116,13 +214,13 @@ pub fn import_image_data(
         match image.source() {
             image::Source::Uri { uri, mime_type } if base.is_some() => {
                 match Scheme::parse(uri) {
-                    Scheme::Data(Some(annoying_case), base64) => {
+                    Scheme::Data { mime_type: Some(annoying_case), base64, data } => {
                         let format = match annoying_case.as_ref() {
                             "image/png" => Png,
                             "image/jpeg" => Jpeg,
                             _ => return Err(Error::UnsupportedImageEncoding),
                         };
-                        let encoded_image = base64::decode(&base64).map_err(Error::Base64)?;
+                        let encoded_image = decode_data(base64, data)?;
                         let decoded_image = image_crate::load_from_memory_with_format(&encoded_image, format)?;
                         images.push(image::Data::new(decoded_image));
                         continue;
@@ -164,18 +262,23 @@ pub fn import_image_data(
     Ok(images)
 }
 
-fn import_impl(Gltf { document, blob }: Gltf, base: Option<&Path>) -> Result<Import> {
+fn import_impl(
+    Gltf { mut document, blob }: Gltf,
+    base: Option<&Path>,
+    options: &ImportOptions,
+) -> Result<Import> {
+    options.apply(&mut document);
     let buffer_data = import_buffer_data(&document, base, blob)?;
     let image_data = import_image_data(&document, base, &buffer_data)?;
     let import = (document, buffer_data, image_data);
     Ok(import)
 }
 
-fn import_path(path: &Path) -> Result<Import> {
+fn import_path(path: &Path, options: &ImportOptions) -> Result<Import> {
     let base = path.parent().unwrap_or(Path::new("./"));
     let file = fs::File::open(path).map_err(Error::Io)?;
     let reader = io::BufReader::new(file);
-    import_impl(Gltf::from_reader(reader)?, Some(base))
+    import_impl(Gltf::from_reader(reader)?, Some(base), options)
 }
 
 /// Import some glTF 2.0 from the file system.
@@ -208,11 +311,21 @@ fn import_path(path: &Path) -> Result<Import> {
 pub fn import<P>(path: P) -> Result<Import>
     where P: AsRef<Path>
 {
-    import_path(path.as_ref())
+    import_path(path.as_ref(), &ImportOptions::default())
+}
+
+/// Import some glTF 2.0 from the file system, as with [`import`], with the
+/// given options.
+///
+/// [`import`]: fn.import.html
+pub fn import_with_options<P>(path: P, options: &ImportOptions) -> Result<Import>
+    where P: AsRef<Path>
+{
+    import_path(path.as_ref(), options)
 }
 
 pub fn import_slice_impl(slice: &[u8]) -> Result<Import> {
-    import_impl(Gltf::from_slice(slice)?, None)
+    import_impl(Gltf::from_slice(slice)?, None, &ImportOptions::default())
 }
 
 /// Import some glTF 2.0 from a slice
@@ -239,3 +352,65 @@ pub fn import_slice<S>(slice: S) -> Result<Import>
 {
     import_slice_impl(slice.as_ref())
 }
+
+/// Import some glTF 2.0 from a slice, as with [`import_slice`], with the
+/// given options.
+///
+/// [`import_slice`]: fn.import_slice.html
+pub fn import_slice_with_options<S>(slice: S, options: &ImportOptions) -> Result<Import>
+    where S: AsRef<[u8]>
+{
+    import_impl(Gltf::from_slice(slice.as_ref())?, None, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_data_uri, import_slice};
+    use crate::binary::{Glb, Header};
+    use crate::{buffer, Error};
+
+    #[test]
+    fn data_uri_base64() {
+        let data = decode_data_uri("data:application/octet-stream;base64,AAECAw==").unwrap();
+        assert_eq!(data, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn data_uri_percent_encoded() {
+        assert_eq!(decode_data_uri("data:,a%20b%00").unwrap(), b"a b\0");
+        assert_eq!(decode_data_uri("data:text/plain;charset=US-ASCII,hi").unwrap(), b"hi");
+    }
+
+    #[test]
+    fn data_uri_malformed_base64() {
+        match decode_data_uri("data:application/octet-stream;base64,!!!") {
+            Err(Error::Base64(_)) => {},
+            other => panic!("expected base 64 error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn data_uri_external() {
+        match decode_data_uri("buffer.bin") {
+            Err(Error::NotDataUri) => {},
+            other => panic!("expected `NotDataUri`, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn glb_buffer_without_uri() {
+        let json = br#"{ "asset": { "version": "2.0" }, "buffers": [ { "byteLength": 4 } ] }"#;
+        let glb = Glb {
+            header: Header { magic: *b"glTF", version: 2, length: 0 },
+            json: json[..].into(),
+            bin: Some(vec![1, 2, 3, 4].into()),
+        };
+        let (document, buffers, _) = import_slice(glb.to_vec().unwrap()).unwrap();
+        match document.buffers().next().unwrap().source() {
+            buffer::Source::Bin => {},
+            other => panic!("expected `Bin`, found {:?}", other),
+        }
+        assert_eq!(&*buffers[0], &[1, 2, 3, 4]);
+        assert!(!document.0.to_string().unwrap().contains("uri"));
+    }
+}
@@ -122,6 +122,10 @@ pub mod material;
 /// For internal use.
 mod math;
 
+/// Wavefront OBJ export for debugging.
+#[cfg(feature = "obj")]
+mod obj;
+
 /// Meshes and their primitives.
 pub mod mesh;
 
@@ -152,6 +156,9 @@ pub use self::import::import;
 #[cfg(feature = "import_data_reference")]
 #[doc(inline)]
 pub use self::import::import_slice;
+#[cfg(feature = "import_data_reference")]
+#[doc(inline)]
+pub use self::import::{import_slice_with_options, import_with_options, ImportOptions};
 #[doc(inline)]
 pub use self::material::Material;
 #[doc(inline)]
@@ -163,6 +170,7 @@ pub use self::skin::Skin;
 #[doc(inline)]
 pub use self::texture::Texture;
 
+use std::collections::HashMap;
 use std::path::Path;
 use std::{fs, io, ops, result};
 
@@ -214,6 +222,16 @@ pub enum Error {
     #[cfg(feature = "import")]
     ExternalReferenceInSliceImport,
 
+    /// Data was requested from a URI that does not use the `data:` scheme.
+    #[cfg(feature = "import")]
+    NotDataUri,
+
+    /// JSON serialization error.
+    Serialize(json::Error),
+
+    /// A required extension is not supported.
+    UnsupportedExtension(String),
+
     /// Unsupported image encoding.
     #[cfg(feature = "import")]
     UnsupportedImageEncoding,
@@ -222,6 +240,9 @@ pub enum Error {
     #[cfg(feature = "import")]
     UnsupportedScheme,
 
+    /// The asset is not glTF 2.0.
+    UnsupportedVersion(String),
+
     /// glTF validation error.
     Validation(Vec<(json::Path, json::validation::Error)>),
 }
@@ -263,11 +284,17 @@ impl Gltf {
         let (json, blob): (json::Root, Option<Vec<u8>>);
         if magic.starts_with(b"glTF") {
             let mut glb = binary::Glb::from_reader(reader)?;
-            // TODO: use `json::from_reader` instead of `json::from_slice`
-            json = json::deserialize::from_slice(&glb.json)?;
+            json = deserialize_json(&glb.json)?;
             blob = glb.bin.take().map(|x| x.into_owned());
         } else {
-            json = json::deserialize::from_reader(reader)?;
+            json = match json::deserialize::from_reader(&mut reader) {
+                Ok(root) => check_version(root)?,
+                Err(error) => {
+                    reader.seek(io::SeekFrom::Start(0))?;
+                    let version = json::Root::asset_version_from_reader(reader).ok();
+                    return Err(deserialize_error(error, version));
+                },
+            };
             blob = None;
         };
         let document = Document::from_json_without_validation(json);
@@ -290,10 +317,10 @@ impl Gltf {
         let (json, blob): (json::Root, Option<Vec<u8>>);
         if slice.starts_with(b"glTF") {
             let mut glb = binary::Glb::from_slice(slice)?;
-            json = json::deserialize::from_slice(&glb.json)?;
+            json = deserialize_json(&glb.json)?;
             blob = glb.bin.take().map(|x| x.into_owned());
         } else {
-            json = json::deserialize::from_slice(slice)?;
+            json = deserialize_json(slice)?;
             blob = None;
         };
         let document = Document::from_json_without_validation(json);
@@ -308,6 +335,47 @@ impl Gltf {
     }
 }
 
+/// Deserializes glTF JSON, reporting assets of other major versions as
+/// `Error::UnsupportedVersion` rather than whatever error their layout
+/// happens to produce.
+fn deserialize_json(slice: &[u8]) -> Result<json::Root> {
+    match json::deserialize::from_slice(slice) {
+        Ok(root) => check_version(root),
+        Err(error) => Err(deserialize_error(error, json::Root::asset_version(slice).ok())),
+    }
+}
+
+/// Rejects assets that are not glTF 2.0, or whose `minVersion` is newer than
+/// glTF 2.0, with `Error::UnsupportedVersion`.
+fn check_version(root: json::Root) -> Result<json::Root> {
+    if !is_version_2(&root.asset.version) {
+        return Err(Error::UnsupportedVersion(root.asset.version));
+    }
+    if let Some(min_version) = root.asset.min_version.as_ref() {
+        match json::asset::parse_version(min_version) {
+            Some(number) if number > (2, 0) => {
+                return Err(Error::UnsupportedVersion(min_version.clone()));
+            },
+            _ => {},
+        }
+    }
+    Ok(root)
+}
+
+/// Reports a deserialization failure as `Error::UnsupportedVersion` when the
+/// separately read `asset.version` names another major version, since their
+/// layouts differ from glTF 2.0.
+fn deserialize_error(error: json::Error, version: Option<String>) -> Error {
+    match version {
+        Some(version) if !is_version_2(&version) => Error::UnsupportedVersion(version),
+        _ => Error::Deserialize(error),
+    }
+}
+
+fn is_version_2(version: &str) -> bool {
+    version.split('.').next() == Some("2")
+}
+
 impl ops::Deref for Gltf {
     type Target = Document;
     fn deref(&self) -> &Self::Target {
@@ -329,6 +397,23 @@ impl Document {
         Ok(document)
     }
 
+    /// Loads standard glTF JSON from a stream of data.
+    ///
+    /// Unlike [`Gltf::from_reader`], the reader need not implement `Seek`,
+    /// but binary glTF is not supported. Since the stream is read only once,
+    /// a document of another glTF major version whose layout fails to
+    /// deserialize is reported as `Error::Deserialize` rather than
+    /// `Error::UnsupportedVersion`.
+    ///
+    /// [`Gltf::from_reader`]: struct.Gltf.html#method.from_reader
+    pub fn from_reader<R>(reader: R) -> Result<Self>
+    where
+        R: io::Read,
+    {
+        let json = check_version(json::deserialize::from_reader(reader)?)?;
+        Self::from_json(json)
+    }
+
     /// Loads glTF from pre-deserialized JSON without performing
     /// validation checks.
     pub fn from_json_without_validation(json: json::Root) -> Self {
@@ -341,7 +426,10 @@ impl Document {
     }
 
     /// Perform validation checks on loaded glTF.
-    pub(crate) fn validate(&self) -> Result<()> {
+    ///
+    /// All problems are collected in a single pass and returned together as
+    /// `Error::Validation`.
+    pub fn validate(&self) -> Result<()> {
         use json::validation::Validate;
         let mut errors = Vec::new();
         self.0.validate(
@@ -356,6 +444,87 @@ impl Document {
         }
     }
 
+    /// Validates the glTF and serializes it as a `String` of JSON.
+    ///
+    /// Nothing is serialized if validation fails, so invalid documents are
+    /// never emitted.
+    pub fn to_string(&self) -> Result<String> {
+        self.validate()?;
+        self.0.to_string().map_err(Error::Serialize)
+    }
+
+    /// Validates the glTF and serializes it as JSON to the given writer.
+    ///
+    /// Nothing is written if validation fails.
+    pub fn to_writer<W>(&self, writer: W) -> Result<()>
+    where
+        W: io::Write,
+    {
+        self.validate()?;
+        self.0.to_writer(writer).map_err(Error::Serialize)
+    }
+
+    /// Validates the glTF and serializes it as a pretty-printed `String` of
+    /// JSON.
+    ///
+    /// Nothing is serialized if validation fails.
+    pub fn to_string_pretty(&self) -> Result<String> {
+        self.validate()?;
+        self.0.to_string_pretty().map_err(Error::Serialize)
+    }
+
+    /// Validates the glTF and serializes it as pretty-printed JSON to the
+    /// given writer.
+    ///
+    /// Nothing is written if validation fails.
+    pub fn to_writer_pretty<W>(&self, writer: W) -> Result<()>
+    where
+        W: io::Write,
+    {
+        self.validate()?;
+        self.0.to_writer_pretty(writer).map_err(Error::Serialize)
+    }
+
+    /// Validates the glTF and packs it together with `bin` as binary glTF.
+    ///
+    /// The first buffer stands for the BIN chunk, so it must have no `uri`
+    /// and a `byteLength` equal to `bin.len()`. When `bin` is empty and there
+    /// are no buffers, no BIN chunk is written.
+    pub fn to_glb(&self, bin: &[u8]) -> Result<Vec<u8>> {
+        self.validate()?;
+        let path = || json::Path::new().field("buffers").index(0);
+        let mut errors = Vec::new();
+        match self.0.buffers.first() {
+            Some(buffer) => {
+                if buffer.uri.is_some() {
+                    errors.push((path().field("uri"), json::validation::Error::Invalid));
+                }
+                if buffer.byte_length as usize != bin.len() {
+                    errors.push((path().field("byteLength"), json::validation::Error::Invalid));
+                }
+            },
+            None if !bin.is_empty() => {
+                errors.push((json::Path::new().field("buffers"), json::validation::Error::Missing));
+            },
+            None => {},
+        }
+        if !errors.is_empty() {
+            return Err(Error::Validation(errors));
+        }
+
+        let json = self.0.to_vec().map_err(Error::Serialize)?;
+        let glb = binary::Glb {
+            header: binary::Header {
+                magic: *b"glTF",
+                version: 2,
+                length: 0,
+            },
+            json: json.into(),
+            bin: if self.0.buffers.is_empty() { None } else { Some(bin.into()) },
+        };
+        glb.to_vec()
+    }
+
     /// Returns an `Iterator` that visits the accessors of the glTF asset.
     pub fn accessors(&self) -> iter::Accessors {
         iter::Accessors {
@@ -364,6 +533,17 @@ impl Document {
         }
     }
 
+    /// Returns the number of accessors for each combination of dimensions,
+    /// component data type, and normalization used in the glTF asset.
+    pub fn accessor_histogram(&self) -> HashMap<(accessor::Dimensions, accessor::DataType, bool), usize> {
+        let mut histogram = HashMap::new();
+        for accessor in self.accessors() {
+            let key = (accessor.dimensions(), accessor.data_type(), accessor.normalized());
+            *histogram.entry(key).or_insert(0) += 1;
+        }
+        histogram
+    }
+
     /// Returns an `Iterator` that visits the animations of the glTF asset.
     pub fn animations(&self) -> iter::Animations {
         iter::Animations {
@@ -389,11 +569,14 @@ impl Document {
     }
 
     /// Returns the default scene, if provided.
+    ///
+    /// Returns `None` when the default scene index is out of range, which is
+    /// only possible for documents that were not validated.
     pub fn default_scene(&self) -> Option<Scene> {
         self.0
             .scene
             .as_ref()
-            .map(|index| self.scenes().nth(index.value()).unwrap())
+            .and_then(|index| self.scenes().nth(index.value()))
     }
 
     /// Returns the extensions referenced in this .document file.
@@ -406,6 +589,17 @@ impl Document {
         iter::ExtensionsRequired(self.0.extensions_required.iter())
     }
 
+    /// Checks that every extension required by this asset is in the given
+    /// list of supported extensions.
+    ///
+    /// Returns `Error::UnsupportedExtension` naming the first required
+    /// extension that is not supported.
+    pub fn check_required_extensions(&self, supported: &[&str]) -> Result<()> {
+        self.0
+            .check_required_extensions(supported)
+            .map_err(|name| Error::UnsupportedExtension(name.to_string()))
+    }
+
     /// Returns an `Iterator` that visits the pre-loaded images of the glTF asset.
     pub fn images(&self) -> iter::Images {
         iter::Images {
@@ -523,9 +717,14 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "import")]
             Error::ExternalReferenceInSliceImport => write!(f, "external reference in slice only import"),
             #[cfg(feature = "import")]
+            Error::NotDataUri => write!(f, "URI does not use the data scheme"),
+            Error::Serialize(ref e) => e.fmt(f),
+            Error::UnsupportedExtension(ref name) => write!(f, "unsupported required extension: {}", name),
+            #[cfg(feature = "import")]
             Error::UnsupportedImageEncoding => write!(f, "unsupported image encoding"),
             #[cfg(feature = "import")]
             Error::UnsupportedScheme => write!(f, "unsupported URI scheme"),
+            Error::UnsupportedVersion(ref version) => write!(f, "unsupported glTF version {}", version),
             Error::Validation(ref xs) => {
                 write!(f, "invalid glTF:")?;
                 for &(ref path, ref error) in xs {
@@ -554,12 +753,31 @@ impl std::error::Error for Error {
             #[cfg(feature = "import")]
             Error::ExternalReferenceInSliceImport => "external reference in slice only import",
             #[cfg(feature = "import")]
+            Error::NotDataUri => "URI does not use the data scheme",
+            Error::Serialize(ref e) => e.description(),
+            Error::UnsupportedExtension(_) => "unsupported required extension",
+            #[cfg(feature = "import")]
             Error::UnsupportedImageEncoding => "unsupported image encoding",
             #[cfg(feature = "import")]
             Error::UnsupportedScheme => "unsupported URI scheme",
+            Error::UnsupportedVersion(_) => "unsupported glTF version",
             Error::Validation(_) => "invalid glTF",
         }
     }
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            #[cfg(feature = "import_data_reference")]
+            Error::Base64(ref e) => Some(e),
+            Error::Binary(ref e) => Some(e),
+            Error::Deserialize(ref e) => Some(e),
+            Error::Io(ref e) => Some(e),
+            #[cfg(feature = "import_data_reference")]
+            Error::Image(ref e) => Some(e),
+            Error::Serialize(ref e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 impl From<binary::Error> for Error {
@@ -630,7 +848,7 @@ impl Normalize<u16> for u8 {
 }
 
 impl Normalize<f32> for u8 {
-    fn normalize(self) -> f32 { (self as f32 * 32767.0_f32.recip()).max(-1.0) }
+    fn normalize(self) -> f32 { self as f32 * 255.0_f32.recip() }
 }
 
 impl Normalize<i8> for i16 {
@@ -71,6 +71,20 @@ impl<'a> Material<'a> {
         self.json.alpha_mode.unwrap()
     }
 
+    /// Returns `true` if the material should be rendered with blending, that is
+    /// when the alpha mode is `Blend`.
+    ///
+    /// The base color alpha is ignored in the other modes, so an `Opaque`
+    /// material with an alpha factor below one is not transparent.
+    pub fn is_transparent(&self) -> bool {
+        self.alpha_mode() == AlphaMode::Blend
+    }
+
+    /// Returns `true` if the alpha mode is `Mask`.
+    pub fn is_masked(&self) -> bool {
+        self.alpha_mode() == AlphaMode::Mask
+    }
+
     /// Specifies whether the material is double-sided.
     ///
     /// * When this value is false, back-face culling is enabled.
@@ -84,7 +98,7 @@ impl<'a> Material<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// Parameter values that define the metallic-roughness material model from
@@ -407,3 +421,114 @@ impl<'a> AsRef<texture::Texture<'a>> for OcclusionTexture<'a> {
         &self.texture
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Gltf;
+
+    #[test]
+    fn alpha_classification() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "materials": [
+                {},
+                { "alphaMode": "MASK", "alphaCutoff": 0.25 },
+                { "alphaMode": "BLEND" },
+                { "pbrMetallicRoughness": { "baseColorFactor": [ 1.0, 1.0, 1.0, 0.5 ] } },
+                {
+                    "alphaMode": "BLEND",
+                    "pbrMetallicRoughness": { "baseColorFactor": [ 1.0, 1.0, 1.0, 0.5 ] }
+                }
+            ]
+        }"#).unwrap();
+        let materials = gltf.materials().collect::<Vec<_>>();
+
+        assert!(!materials[0].is_transparent());
+        assert!(!materials[0].is_masked());
+
+        assert!(!materials[1].is_transparent());
+        assert!(materials[1].is_masked());
+
+        assert!(materials[2].is_transparent());
+        assert!(!materials[2].is_masked());
+
+        // `OPAQUE` ignores the alpha of the base color.
+        assert!(!materials[3].is_transparent());
+        assert!(!materials[3].is_masked());
+
+        assert!(materials[4].is_transparent());
+        assert!(!materials[4].is_masked());
+    }
+
+    #[test]
+    fn optional_textures() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "images": [ { "uri": "texture.png" } ],
+            "textures": [ { "source": 0 } ],
+            "materials": [
+                { "pbrMetallicRoughness": { "baseColorFactor": [ 1.0, 0.0, 0.0, 1.0 ] } },
+                { "pbrMetallicRoughness": { "metallicRoughnessTexture": { "index": 0, "texCoord": 1 } } }
+            ]
+        }"#).unwrap();
+        let materials = gltf.materials().collect::<Vec<_>>();
+
+        let pbr = materials[0].pbr_metallic_roughness();
+        assert_eq!(pbr.base_color_factor(), [1.0, 0.0, 0.0, 1.0]);
+        assert!(pbr.base_color_texture().is_none());
+        assert!(pbr.metallic_roughness_texture().is_none());
+        assert!(materials[0].normal_texture().is_none());
+        assert!(materials[0].occlusion_texture().is_none());
+        assert!(materials[0].emissive_texture().is_none());
+
+        let pbr = materials[1].pbr_metallic_roughness();
+        assert!(pbr.base_color_texture().is_none());
+        let info = pbr.metallic_roughness_texture().unwrap();
+        assert_eq!(info.texture().index(), 0);
+        assert_eq!(info.tex_coord(), 1);
+    }
+
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    #[test]
+    fn pbr_specular_glossiness() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "extensionsUsed": [ "KHR_materials_pbrSpecularGlossiness" ],
+            "images": [ { "uri": "texture.png" } ],
+            "textures": [ { "source": 0 } ],
+            "materials": [
+                {},
+                {
+                    "extensions": {
+                        "KHR_materials_pbrSpecularGlossiness": {
+                            "diffuseFactor": [ 0.5, 0.5, 0.5, 1.0 ],
+                            "specularFactor": [ 0.0, 0.0, 0.0 ],
+                            "glossinessFactor": 0.25,
+                            "specularGlossinessTexture": { "index": 0 }
+                        }
+                    }
+                }
+            ]
+        }"#).unwrap();
+        let materials = gltf.materials().collect::<Vec<_>>();
+        assert!(materials[0].pbr_specular_glossiness().is_none());
+
+        let pbr = materials[1].pbr_specular_glossiness().unwrap();
+        assert_eq!(pbr.diffuse_factor(), [0.5, 0.5, 0.5, 1.0]);
+        assert!(pbr.diffuse_texture().is_none());
+        assert_eq!(pbr.specular_factor(), [0.0, 0.0, 0.0]);
+        assert_eq!(pbr.glossiness_factor(), 0.25);
+        assert_eq!(pbr.specular_glossiness_texture().unwrap().texture().index(), 0);
+
+        let malformed = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "materials": [
+                { "extensions": { "KHR_materials_pbrSpecularGlossiness": { "glossinessFactor": "high" } } }
+            ]
+        }"#);
+        match malformed {
+            Err(crate::Error::Deserialize(_)) => {},
+            other => panic!("expected deserialization error, found {:?}", other),
+        }
+    }
+}
@@ -225,6 +225,43 @@ impl Matrix3 {
         }
     }
 
+    /// Create a matrix from the upper-left 3 x 3 of a column-major 4 x 4 array.
+    pub fn from_upper_left(m: &[[f32; 4]; 4]) -> Matrix3 {
+        Matrix3::new(
+            m[0][0], m[0][1], m[0][2],
+            m[1][0], m[1][1], m[1][2],
+            m[2][0], m[2][1], m[2][2],
+        )
+    }
+
+    pub fn transpose(&self) -> Matrix3 {
+        Matrix3::new(
+            self.x.x, self.y.x, self.z.x,
+            self.x.y, self.y.y, self.z.y,
+            self.x.z, self.y.z, self.z.z,
+        )
+    }
+
+    /// Returns the inverse of the matrix, or `None` if it is singular.
+    pub fn invert(&self) -> Option<Matrix3> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+        // The rows of the inverse are the cross products of the columns.
+        let cross = |a: Vector3, b: Vector3| Vector3::new(
+            a.y * b.z - a.z * b.y,
+            a.z * b.x - a.x * b.z,
+            a.x * b.y - a.y * b.x,
+        );
+        let rows = Matrix3 {
+            x: cross(self.y, self.z) * (1.0 / det),
+            y: cross(self.z, self.x) * (1.0 / det),
+            z: cross(self.x, self.y) * (1.0 / det),
+        };
+        Some(rows.transpose())
+    }
+
     pub fn determinant(&self) -> f32 {
         self.x.x * (self.y.y * self.z.z - self.z.y * self.y.z)
             - self.y.x * (self.x.y * self.z.z - self.z.y * self.x.z)
@@ -260,7 +297,7 @@ impl Matrix4 {
         }
     }
 
-    #[cfg(test)]
+    /// Create a matrix from a column-major array.
     pub fn from_array(m: [[f32; 4]; 4]) -> Matrix4 {
         Matrix4::new(
             m[0][0], m[0][1], m[0][2], m[0][3],
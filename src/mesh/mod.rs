@@ -155,7 +155,7 @@ impl<'a> Mesh<'a>  {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// Defines the geometry to be renderered with a material.
@@ -0,0 +1,237 @@
+use std::fmt::Write;
+
+use crate::math::Matrix3;
+use crate::mesh::Mode;
+use crate::{buffer, Document, Node, Scene};
+
+/// Running state of an OBJ export.
+#[derive(Default)]
+struct Obj {
+    /// The OBJ text written so far.
+    output: String,
+
+    /// Number of `v` lines written so far.
+    positions: u32,
+
+    /// Number of `vt` lines written so far.
+    tex_coords: u32,
+
+    /// Number of `vn` lines written so far.
+    normals: u32,
+}
+
+/// Transforms a point by a column-major 4x4 matrix.
+fn transform_point(m: &[[f32; 4]; 4], p: [f32; 3]) -> [f32; 3] {
+    [
+        m[0][0] * p[0] + m[1][0] * p[1] + m[2][0] * p[2] + m[3][0],
+        m[0][1] * p[0] + m[1][1] * p[1] + m[2][1] * p[2] + m[3][1],
+        m[0][2] * p[0] + m[1][2] * p[1] + m[2][2] * p[2] + m[3][2],
+    ]
+}
+
+/// Returns the matrix that transforms normals under a column-major 4x4
+/// matrix, i.e. the inverse-transpose of its upper 3x3.
+fn normal_matrix(m: &[[f32; 4]; 4]) -> Matrix3 {
+    let m = Matrix3::from_upper_left(m);
+    // A singular matrix collapses the geometry anyway.
+    m.invert().map_or(m, |inverse| inverse.transpose())
+}
+
+/// Transforms a normal by a normal matrix and re-normalizes the result.
+fn transform_normal(m: &Matrix3, n: [f32; 3]) -> [f32; 3] {
+    let v = [
+        m.x.x * n[0] + m.y.x * n[1] + m.z.x * n[2],
+        m.x.y * n[0] + m.y.y * n[1] + m.z.y * n[2],
+        m.x.z * n[0] + m.y.z * n[1] + m.z.z * n[2],
+    ];
+    let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    if length > 0.0 {
+        [v[0] / length, v[1] / length, v[2] / length]
+    } else {
+        v
+    }
+}
+
+impl Obj {
+    fn write_node(&mut self, node: &Node, matrix: &[[f32; 4]; 4], buffers: &[buffer::Data]) {
+        if let Some(mesh) = node.mesh() {
+            let normal_matrix = normal_matrix(matrix);
+            for primitive in mesh.primitives() {
+                if primitive.mode() != Mode::Triangles {
+                    continue;
+                }
+                let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(|data| &data[..]));
+                let positions = match reader.read_positions() {
+                    Some(iter) => iter.collect::<Vec<_>>(),
+                    None => continue,
+                };
+                let normals = reader
+                    .read_normals()
+                    .map(|iter| iter.collect::<Vec<_>>())
+                    .filter(|normals| normals.len() == positions.len());
+                let tex_coords = reader
+                    .read_tex_coords(0)
+                    .map(|iter| iter.into_f32().collect::<Vec<_>>())
+                    .filter(|tex_coords| tex_coords.len() == positions.len());
+                let indices = reader
+                    .read_indices()
+                    .map(|iter| iter.into_u32().collect::<Vec<_>>())
+                    .unwrap_or_else(|| (0..positions.len() as u32).collect());
+
+                let _ = writeln!(self.output, "o mesh{}.primitive{}", mesh.index(), primitive.index());
+                for &p in &positions {
+                    let [x, y, z] = transform_point(matrix, p);
+                    let _ = writeln!(self.output, "v {} {} {}", x, y, z);
+                }
+                if let Some(tex_coords) = tex_coords.as_ref() {
+                    for &[u, v] in tex_coords {
+                        // OBJ texture co-ordinates have their origin at the bottom-left.
+                        let _ = writeln!(self.output, "vt {} {}", u, 1.0 - v);
+                    }
+                }
+                if let Some(normals) = normals.as_ref() {
+                    for &n in normals {
+                        let [x, y, z] = transform_normal(&normal_matrix, n);
+                        let _ = writeln!(self.output, "vn {} {} {}", x, y, z);
+                    }
+                }
+
+                for triangle in indices.chunks(3).filter(|t| t.len() == 3) {
+                    self.output.push('f');
+                    for &i in triangle {
+                        // OBJ indices are one-based and global to the file.
+                        let v = self.positions + i + 1;
+                        let _ = match (tex_coords.is_some(), normals.is_some()) {
+                            (true, true) => write!(
+                                self.output,
+                                " {}/{}/{}",
+                                v,
+                                self.tex_coords + i + 1,
+                                self.normals + i + 1,
+                            ),
+                            (true, false) => write!(self.output, " {}/{}", v, self.tex_coords + i + 1),
+                            (false, true) => write!(self.output, " {}//{}", v, self.normals + i + 1),
+                            (false, false) => write!(self.output, " {}", v),
+                        };
+                    }
+                    self.output.push('\n');
+                }
+
+                self.positions += positions.len() as u32;
+                self.tex_coords += tex_coords.map_or(0, |x| x.len() as u32);
+                self.normals += normals.map_or(0, |x| x.len() as u32);
+            }
+        }
+    }
+}
+
+impl Document {
+    /// Dumps the world-space triangle geometry of a scene as Wavefront OBJ
+    /// text.
+    ///
+    /// Vertex positions, normals, and the first set of texture co-ordinates
+    /// are written for every primitive with mode `Triangles`; other modes are
+    /// skipped. This is intended as a debugging aid for verifying imports
+    /// rather than a general purpose converter.
+    pub fn export_obj(&self, scene: &Scene, buffers: &[buffer::Data]) -> String {
+        let mut obj = Obj::default();
+        scene.walk(|node, matrix| obj.write_node(node, matrix, buffers));
+        obj.output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{buffer, Gltf};
+
+    fn triangle() -> (Gltf, Vec<buffer::Data>) {
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "scene": 0,
+            "scenes": [ { "nodes": [ 0 ] } ],
+            "nodes": [ { "mesh": 0, "translation": [ 1.0, 0.0, 0.0 ] } ],
+            "meshes": [ { "primitives": [ { "attributes": { "POSITION": 0 }, "indices": 1 } ] } ],
+            "buffers": [ { "byteLength": 44 } ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+                { "buffer": 0, "byteOffset": 36, "byteLength": 6 }
+            ],
+            "accessors": [
+                {
+                    "bufferView": 0,
+                    "componentType": 5126,
+                    "count": 3,
+                    "type": "VEC3",
+                    "min": [ 0.0, 0.0, 0.0 ],
+                    "max": [ 1.0, 1.0, 0.0 ]
+                },
+                { "bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR" }
+            ]
+        }"#;
+        let mut data = Vec::new();
+        for &f in &[0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0] {
+            data.extend_from_slice(&f.to_bits().to_le_bytes());
+        }
+        for &i in &[0u16, 1, 2] {
+            data.extend_from_slice(&i.to_le_bytes());
+        }
+        data.extend_from_slice(&[0, 0]);
+        (Gltf::from_slice(json.as_bytes()).unwrap(), vec![buffer::Data(data)])
+    }
+
+    #[test]
+    fn export_triangle() {
+        let (gltf, buffers) = triangle();
+        let scene = gltf.default_scene().unwrap();
+        let obj = gltf.export_obj(&scene, &buffers);
+        let lines = obj.lines().filter(|line| !line.starts_with('o')).collect::<Vec<_>>();
+        assert_eq!(lines, vec!["v 1 0 0", "v 2 0 0", "v 1 1 0", "f 1 2 3"]);
+    }
+
+    #[test]
+    fn export_normals_nonuniform_scale() {
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "scene": 0,
+            "scenes": [ { "nodes": [ 0 ] } ],
+            "nodes": [ { "mesh": 0, "scale": [ 2.0, 1.0, 1.0 ] } ],
+            "meshes": [ { "primitives": [ { "attributes": { "POSITION": 0, "NORMAL": 1 } } ] } ],
+            "buffers": [ { "byteLength": 72 } ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+                { "buffer": 0, "byteOffset": 36, "byteLength": 36 }
+            ],
+            "accessors": [
+                {
+                    "bufferView": 0,
+                    "componentType": 5126,
+                    "count": 3,
+                    "type": "VEC3",
+                    "min": [ 0.0, 0.0, 0.0 ],
+                    "max": [ 1.0, 1.0, 1.0 ]
+                },
+                { "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3" }
+            ]
+        }"#;
+        // A triangle in the plane `x + y = 1`.
+        let n = 0.5f32.sqrt();
+        let mut data = Vec::new();
+        for &f in &[1.0f32, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, n, n, 0.0, n, n, 0.0, n, n, 0.0] {
+            data.extend_from_slice(&f.to_bits().to_le_bytes());
+        }
+        let gltf = Gltf::from_slice(json.as_bytes()).unwrap();
+        let scene = gltf.default_scene().unwrap();
+        let obj = gltf.export_obj(&scene, &[buffer::Data(data)]);
+
+        // Scaled by two along x, the plane becomes `x / 2 + y = 1`.
+        let expected = [1.0 / 5.0f32.sqrt(), 2.0 / 5.0f32.sqrt(), 0.0];
+        let normals = obj.lines().filter(|line| line.starts_with("vn ")).collect::<Vec<_>>();
+        assert_eq!(normals.len(), 3);
+        for line in normals {
+            let normal = line[3..].split(' ').map(|x| x.parse::<f32>().unwrap()).collect::<Vec<_>>();
+            for (x, y) in normal.iter().zip(&expected) {
+                assert!((x - y).abs() < 1e-6, "{} != {:?}", line, expected);
+            }
+        }
+    }
+}
@@ -168,7 +168,7 @@ impl<'a> Node<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// Returns the node's transform.
@@ -205,6 +205,59 @@ impl<'a> Node<'a> {
     pub fn weights(&self) -> Option<&[f32]> {
         self.json.weights.as_ref().map(Vec::as_slice)
     }
+
+    /// Returns the world transform of this node.
+    ///
+    /// The world transform is the product of the local transforms from the
+    /// root of the node hierarchy down to this node, i.e.
+    /// `root * ... * parent * node` in column-major order.
+    ///
+    /// This builds a lookup of node parents on every call; when transforming
+    /// many nodes, compute [`json::Root::parent_map`] once and use
+    /// [`world_transform_with`] instead.
+    ///
+    /// [`json::Root::parent_map`]: ../json/struct.Root.html#method.parent_map
+    /// [`world_transform_with`]: #method.world_transform_with
+    pub fn world_transform(&self) -> [[f32; 4]; 4] {
+        // Unlike `parent_map`, tolerate nodes with several parents, which
+        // validation rejects, by keeping the first parent found.
+        let mut parents = vec![None; self.document.0.nodes.len()];
+        for (index, node) in self.document.0.nodes.iter().enumerate() {
+            for child in node.children.iter().flatten() {
+                if let Some(parent @ None) = parents.get_mut(child.value()) {
+                    *parent = Some(json::Index::new(index as u32));
+                }
+            }
+        }
+        self.world_transform_with(&parents)
+    }
+
+    /// Returns the world transform of this node, given the parent of every
+    /// node as returned by [`json::Root::parent_map`].
+    ///
+    /// [`json::Root::parent_map`]: ../json/struct.Root.html#method.parent_map
+    pub fn world_transform_with(&self, parents: &[Option<json::Index<json::Node>>]) -> [[f32; 4]; 4] {
+        let nodes = &self.document.0.nodes;
+        let mut world = Matrix4::from_array(self.transform().matrix());
+        let mut index = self.index;
+        // Climb at most once per node so that cycles, which validation
+        // rejects, cannot cause infinite loops.
+        for _ in 0..nodes.len() {
+            let parent = parents
+                .get(index)
+                .and_then(|parent| *parent)
+                .and_then(|parent| nodes.get(parent.value()).map(|json| (parent.value(), json)));
+            match parent {
+                Some((parent, json)) => {
+                    let node = Node::new(self.document, parent, json);
+                    world = Matrix4::from_array(node.transform().matrix()) * world;
+                    index = parent;
+                },
+                None => break,
+            }
+        }
+        world.as_array()
+    }
 }
 
 impl<'a> Scene<'a> {
@@ -234,7 +287,7 @@ impl<'a> Scene<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// Returns an `Iterator` that visits each root node of the scene.
@@ -244,14 +297,167 @@ impl<'a> Scene<'a> {
             iter: self.json.nodes.iter(),
         }
     }
+
+    /// Returns `true` if the given node is reachable from the root nodes of
+    /// this scene.
+    pub fn contains(&self, node: &Node) -> bool {
+        let nodes = &self.document.0.nodes;
+        let mut visited = vec![false; nodes.len()];
+        let mut stack: Vec<usize> = self.json.nodes.iter().map(|index| index.value()).collect();
+        while let Some(index) = stack.pop() {
+            if index == node.index() {
+                return true;
+            }
+            if index >= nodes.len() || visited[index] {
+                continue;
+            }
+            visited[index] = true;
+            if let Some(children) = nodes[index].children.as_ref() {
+                stack.extend(children.iter().map(|child| child.value()));
+            }
+        }
+        false
+    }
+
+    /// Visits every node reachable from the root nodes of this scene in
+    /// depth-first order, together with its world transform.
+    ///
+    /// The world transform is the product of the local transforms from the
+    /// root node down to the visited node, in column-major order. Each node
+    /// is visited at most once, so node hierarchies containing cycles, which
+    /// validation rejects, cannot cause infinite loops.
+    pub fn walk<F>(&self, mut f: F)
+        where F: FnMut(&Node<'a>, &[[f32; 4]; 4])
+    {
+        let nodes = &self.document.0.nodes;
+        let mut visited = vec![false; nodes.len()];
+        let identity = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0);
+        let mut stack: Vec<(usize, Matrix4)> = self.json.nodes
+            .iter()
+            .rev()
+            .map(|index| (index.value(), identity))
+            .collect();
+        while let Some((index, parent)) = stack.pop() {
+            if index >= nodes.len() || visited[index] {
+                continue;
+            }
+            visited[index] = true;
+            let node = Node::new(self.document, index, &nodes[index]);
+            let world = parent * Matrix4::from_array(node.transform().matrix());
+            f(&node, &world.as_array());
+            if let Some(children) = nodes[index].children.as_ref() {
+                stack.extend(children.iter().rev().map(|child| (child.value(), world)));
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::scene::Transform;
     use crate::math::*;
+    use crate::Gltf;
     use std::f32::consts::PI;
 
+    #[test]
+    fn scene_contains() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "scenes": [ { "nodes": [ 0 ] }, { "nodes": [ 2 ] } ],
+            "nodes": [ { "children": [ 1 ] }, {}, {} ]
+        }"#).unwrap();
+        let scene = gltf.scenes().next().unwrap();
+        let nodes = gltf.nodes().collect::<Vec<_>>();
+        assert!(scene.contains(&nodes[0]));
+        assert!(scene.contains(&nodes[1]));
+        assert!(!scene.contains(&nodes[2]));
+        assert!(gltf.scenes().nth(1).unwrap().contains(&nodes[2]));
+    }
+
+    #[test]
+    fn node_transform_translation() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "nodes": [
+                { "translation": [ 1.0, 2.0, 3.0 ] },
+                { "matrix": [ 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 2, 3, 1 ] }
+            ]
+        }"#).unwrap();
+        let nodes = gltf.nodes().collect::<Vec<_>>();
+        match nodes[0].transform() {
+            Transform::Decomposed { .. } => {},
+            other => panic!("expected `Decomposed`, found {:?}", other),
+        }
+        match nodes[1].transform() {
+            Transform::Matrix { .. } => {},
+            other => panic!("expected `Matrix`, found {:?}", other),
+        }
+        let matrix = nodes[0].transform().matrix();
+        assert_eq!(matrix, nodes[1].transform().matrix());
+        assert_eq!(matrix[3], [1.0, 2.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn scene_walk() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "scenes": [ { "nodes": [ 0, 2 ] } ],
+            "nodes": [
+                { "translation": [ 1.0, 0.0, 0.0 ], "children": [ 1 ] },
+                { "translation": [ 0.0, 2.0, 0.0 ] },
+                { "scale": [ 2.0, 2.0, 2.0 ] }
+            ]
+        }"#).unwrap();
+        let mut visits = vec![];
+        gltf.scenes().next().unwrap().walk(|node, world| visits.push((node.index(), world[3], world[0][0])));
+        assert_eq!(visits, [
+            (0, [1.0, 0.0, 0.0, 1.0], 1.0),
+            (1, [1.0, 2.0, 0.0, 1.0], 1.0),
+            (2, [0.0, 0.0, 0.0, 1.0], 2.0),
+        ]);
+    }
+
+    #[test]
+    fn scene_walk_without_mesh() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "scenes": [ { "nodes": [ 0 ] } ],
+            "nodes": [
+                {
+                    "children": [ 1 ],
+                    "matrix": [
+                        1.0, 0.0, 0.0, 0.0,
+                        0.0, 1.0, 0.0, 0.0,
+                        0.0, 0.0, 1.0, 0.0,
+                        0.0, 0.0, 5.0, 1.0
+                    ]
+                },
+                { "mesh": 0 }
+            ],
+            "meshes": [ { "primitives": [] } ]
+        }"#).unwrap();
+        let mut visits = vec![];
+        gltf.scenes().next().unwrap().walk(|node, world| {
+            visits.push((node.index(), node.mesh().map(|mesh| mesh.index()), world[3]))
+        });
+        assert_eq!(visits, [
+            (0, None, [0.0, 0.0, 5.0, 1.0]),
+            (1, Some(0), [0.0, 0.0, 5.0, 1.0]),
+        ]);
+    }
+
+    #[test]
+    fn scene_walk_cycle() {
+        let gltf = Gltf::from_slice_without_validation(br#"{
+            "asset": { "version": "2.0" },
+            "scenes": [ { "nodes": [ 0 ] } ],
+            "nodes": [ { "children": [ 1 ] }, { "children": [ 0 ] } ]
+        }"#).unwrap();
+        let mut visits = vec![];
+        gltf.scenes().next().unwrap().walk(|node, _| visits.push(node.index()));
+        assert_eq!(visits, [0, 1]);
+    }
+
     fn rotate(x: f32, y: f32, z: f32, r: f32) -> [f32; 4] {
         let r = Quaternion::from_axis_angle(Vector3::new(x, y, z).normalize(), r);
         [r.v.x, r.v.y, r.v.z, r.s]
@@ -581,4 +787,31 @@ mod tests {
         let scale = [10.0, 0.1, -0.1];
         test_decompose_scale(scale);
     }
+
+    #[test]
+    fn node_world_transform() {
+        let gltf = Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "nodes": [
+                { "translation": [ 0.0, 2.0, 0.0 ], "children": [ 1 ] },
+                { "translation": [ 1.0, 0.0, 0.0 ], "children": [ 2 ] },
+                { "scale": [ 2.0, 2.0, 2.0 ], "children": [ 3 ] },
+                { "translation": [ 1.0, 0.0, 0.0 ] }
+            ]
+        }"#).unwrap();
+        let nodes = gltf.nodes().collect::<Vec<_>>();
+
+        assert_eq!(nodes[0].world_transform()[3], [0.0, 2.0, 0.0, 1.0]);
+        assert_eq!(nodes[1].world_transform()[3], [1.0, 2.0, 0.0, 1.0]);
+
+        // Ancestor transforms apply after the node's own transform.
+        let world = nodes[3].world_transform();
+        assert_eq!(world[0], [2.0, 0.0, 0.0, 0.0]);
+        assert_eq!(world[3], [3.0, 2.0, 0.0, 1.0]);
+
+        let parents = gltf.document.0.parent_map().unwrap();
+        for node in &nodes {
+            assert_eq!(node.world_transform_with(&parents), node.world_transform());
+        }
+    }
 }
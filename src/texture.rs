@@ -16,6 +16,17 @@ pub struct Info<'a> {
     json: &'a json::texture::Info,
 }
 
+/// Many techniques can be used to optimize resource usage for a 3D scene.
+/// Texture atlases and tiling, for example, reuse a texture by transforming
+/// its texture co-ordinates, as defined by the `KHR_texture_transform`
+/// extension.
+#[cfg(feature = "KHR_texture_transform")]
+#[derive(Clone, Debug)]
+pub struct TextureTransform<'a> {
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::texture::TextureTransform,
+}
+
 ///  Texture sampler properties for filtering and wrapping modes.
 #[derive(Clone, Debug)]
 pub struct Sampler<'a> {
@@ -86,7 +97,7 @@ impl<'a> Sampler<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// `s` wrapping mode.
@@ -127,7 +138,7 @@ impl<'a> Texture<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_ref().map(String::as_str)
+        self.json.name.as_ref().map(|name| &**name)
     }
 
     /// Returns the sampler used by this texture.
@@ -139,8 +150,22 @@ impl<'a> Texture<'a> {
     }
 
     /// Returns the image used by this texture.
-    pub fn source(&self) -> image::Image<'a> {
-        self.document.images().nth(self.json.source.value() as usize).unwrap()
+    ///
+    /// Returns `None` when the image is supplied by an extension instead.
+    pub fn source(&self) -> Option<image::Image<'a>> {
+        self.json.source
+            .as_ref()
+            .map(|index| self.document.images().nth(index.value()).unwrap())
+    }
+
+    /// Returns the AVIF image used by this texture as defined by the
+    /// `EXT_texture_avif` extension.
+    #[cfg(feature = "EXT_texture_avif")]
+    pub fn avif_source(&self) -> Option<image::Image<'a>> {
+        self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.ext_texture_avif.as_ref())
+            .map(|avif| self.document.images().nth(avif.source.value()).unwrap())
     }
 
     /// Optional application specific data.
@@ -169,6 +194,51 @@ impl<'a> Info<'a> {
         self.texture.clone()
     }
 
+    /// Returns the texture transform as defined by the `KHR_texture_transform`
+    /// extension.
+    #[cfg(feature = "KHR_texture_transform")]
+    pub fn texture_transform(&self) -> Option<TextureTransform<'a>> {
+        self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.texture_transform.as_ref())
+            .map(TextureTransform::new)
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &json::Extras {
+        &self.json.extras
+    }
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+impl<'a> TextureTransform<'a> {
+    /// Constructs a `TextureTransform`.
+    pub(crate) fn new(json: &'a json::extensions::texture::TextureTransform) -> Self {
+        Self { json }
+    }
+
+    /// The offset of the UV co-ordinate origin as a factor of the texture
+    /// dimensions.
+    pub fn offset(&self) -> [f32; 2] {
+        self.json.offset.0
+    }
+
+    /// Rotates the UVs by this many radians counter-clockwise around the
+    /// origin.
+    pub fn rotation(&self) -> f32 {
+        self.json.rotation
+    }
+
+    /// The scale factor applied to the components of the UV co-ordinates.
+    pub fn scale(&self) -> [f32; 2] {
+        self.json.scale.0
+    }
+
+    /// Overrides the `texCoord` of the texture reference if supplied.
+    pub fn tex_coord(&self) -> Option<u32> {
+        self.json.tex_coord
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
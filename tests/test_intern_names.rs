@@ -0,0 +1,27 @@
+#![cfg(feature = "intern_names")]
+
+use std::fs;
+use std::sync::Arc;
+
+fn node_names((document, _, _): (gltf::Document, Vec<gltf::buffer::Data>, Vec<gltf::image::Data>)) -> (Arc<str>, Arc<str>) {
+    let json = document.into_json();
+    (json.nodes[0].name.clone().unwrap(), json.nodes[1].name.clone().unwrap())
+}
+
+#[test]
+fn test_import_intern_names() {
+    let path = "tests/minimal_duplicate_names.gltf";
+    let interned = gltf::ImportOptions { intern_names: true };
+
+    let (a, b) = node_names(gltf::import_with_options(path, &Default::default()).unwrap());
+    assert_eq!(a, b);
+    assert!(!Arc::ptr_eq(&a, &b));
+
+    let (a, b) = node_names(gltf::import_with_options(path, &interned).unwrap());
+    assert_eq!(&*a, "Bone");
+    assert!(Arc::ptr_eq(&a, &b));
+
+    let slice = fs::read(path).unwrap();
+    let (a, b) = node_names(gltf::import_slice_with_options(&slice, &interned).unwrap());
+    assert!(Arc::ptr_eq(&a, &b));
+}
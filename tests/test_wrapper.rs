@@ -1,6 +1,7 @@
 use std::{fs, io};
 use std::io::Read;
 
+use gltf::accessor::{DataType, Dimensions};
 use gltf::mesh::Bounds;
 
 #[test]
@@ -17,4 +18,661 @@ fn test_accessor_bounds() {
     assert_eq!(bounds, Bounds { min: [-0.03, -0.04, -0.05], max: [1.0, 1.01, 0.02]});
 }
 
+#[test]
+fn test_read_normalized_u8_colors() {
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 32 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 24 },
+            { "buffer": 0, "byteOffset": 24, "byteLength": 8 }
+        ],
+        "accessors": [
+            {
+                "bufferView": 0,
+                "componentType": 5126,
+                "count": 2,
+                "type": "VEC3",
+                "min": [ 0.0, 0.0, 0.0 ],
+                "max": [ 0.0, 0.0, 0.0 ]
+            },
+            { "bufferView": 1, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC4" }
+        ],
+        "meshes": [ { "primitives": [ { "attributes": { "POSITION": 0, "COLOR_0": 1 } } ] } ]
+    }"#).unwrap();
+    let mut data = vec![0; 24];
+    data.extend_from_slice(&[255, 0, 0, 255, 0, 255, 0, 255]);
+    let mesh = gltf.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&data[..]));
+    let colors = reader.read_colors(0).unwrap().into_rgba_f32().collect::<Vec<_>>();
+    assert_eq!(colors, vec![[1.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, 1.0]]);
+}
+
+#[test]
+fn test_accessor_histogram() {
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 4 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 4 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4" },
+            { "bufferView": 0, "componentType": 5121, "normalized": true, "count": 1, "type": "VEC4" },
+            { "bufferView": 0, "componentType": 5121, "normalized": true, "count": 1, "type": "VEC4" },
+            { "bufferView": 0, "componentType": 5123, "count": 2, "type": "SCALAR" }
+        ]
+    }"#).unwrap();
+    let histogram = gltf.accessor_histogram();
+    assert_eq!(histogram.len(), 3);
+    assert_eq!(histogram[&(Dimensions::Vec4, DataType::U8, false)], 1);
+    assert_eq!(histogram[&(Dimensions::Vec4, DataType::U8, true)], 2);
+    assert_eq!(histogram[&(Dimensions::Scalar, DataType::U16, false)], 1);
+}
+
+#[test]
+fn test_from_value() {
+    let file = fs::File::open("tests/minimal_accessor_min_max.gltf").unwrap();
+    let value: gltf::json::Value = gltf::json::deserialize::from_reader(io::BufReader::new(file)).unwrap();
+    assert_eq!(value["asset"]["version"], "2.0");
+    let root = gltf::json::Root::from_value(value).unwrap();
+    let document = gltf::Document::from_json(root).unwrap();
+    assert_eq!(document.meshes().count(), 1);
+}
+
+#[test]
+fn test_invalid_buffer_view_index() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "bufferViews": [],
+        "accessors": [ { "bufferView": 12, "componentType": 5126, "count": 1, "type": "SCALAR" } ]
+    }"#;
+    match gltf::Gltf::from_slice(json) {
+        Err(err @ gltf::Error::Validation(_)) => assert_eq!(
+            err.to_string(),
+            "invalid glTF: accessors[0].bufferView: Index out of bounds (have 0);",
+        ),
+        other => panic!("expected validation error, found {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_document_from_reader() {
+    let file = fs::File::open("tests/minimal_accessor_min_max.gltf").unwrap();
+    let document = gltf::Document::from_reader(io::BufReader::new(file)).unwrap();
+    assert_eq!(document.accessors().count(), 2);
+
+    match gltf::Document::from_reader(&b"{ \"asset\": "[..]) {
+        Err(gltf::Error::Deserialize(_)) => {},
+        other => panic!("expected deserialization error, found {:?}", other.map(|_| ())),
+    }
 
+    // Streamed documents are validated just like those loaded from a slice.
+    let json = br#"{ "asset": { "version": "2.0" }, "scene": 0 }"#;
+    match gltf::Document::from_reader(&json[..]) {
+        Err(gltf::Error::Validation(errors)) => assert_eq!(errors.len(), 1),
+        other => panic!("expected validation error, found {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_import_percent_encoded_uri() {
+    let (document, buffers, _) = gltf::import("tests/minimal_percent_encoded_uri.gltf").unwrap();
+    assert_eq!(buffers.len(), 1);
+    assert!(buffers[0].len() >= document.buffers().next().unwrap().length());
+}
+
+#[test]
+fn test_buffer_decode_uri() {
+    let gltf = gltf::Gltf::open("tests/minimal_accessor_min_max.gltf").unwrap();
+    let buffer = gltf.buffers().next().unwrap();
+    assert_eq!(buffer.decode_uri().unwrap().len(), buffer.length());
+
+    let gltf = gltf::Gltf::open("tests/minimal_percent_encoded_uri.gltf").unwrap();
+    match gltf.buffers().next().unwrap().decode_uri() {
+        Err(gltf::Error::NotDataUri) => {},
+        other => panic!("expected `NotDataUri`, found {:?}", other),
+    }
+
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 8, "uri": "data:application/octet-stream;base64,AAECAw==" } ]
+    }"#).unwrap();
+    match gltf.buffers().next().unwrap().decode_uri() {
+        Err(gltf::Error::BufferLength { buffer: 0, expected: 8, actual: 4 }) => {},
+        other => panic!("expected `BufferLength`, found {:?}", other),
+    }
+}
+
+#[test]
+fn test_validate_collects_all_errors() {
+    let json = gltf::json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "scene": 3,
+        "nodes": [ { "mesh": 1, "camera": 2 } ]
+    }"#).unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+    match document.validate() {
+        Err(gltf::Error::Validation(errors)) => {
+            let paths = errors.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>();
+            assert_eq!(paths, ["scene", "nodes[0].camera", "nodes[0].mesh"]);
+        },
+        other => panic!("expected validation errors, found {:?}", other),
+    }
+}
+
+#[test]
+fn test_import_from_path() {
+    let (document, buffers, images) = gltf::import("tests/minimal_accessor_min_max.gltf").unwrap();
+    assert_eq!(document.meshes().len(), 1);
+    assert_eq!(buffers.len(), 1);
+    assert_eq!(buffers[0].len(), 44);
+    assert!(images.is_empty());
+
+    match gltf::import("tests/does_not_exist.gltf") {
+        Err(gltf::Error::Io(_)) => {},
+        other => panic!("expected I/O error, found {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_accessor_typed_min_max() {
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 12 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 12 } ],
+        "accessors": [
+            {
+                "bufferView": 0,
+                "componentType": 5126,
+                "count": 1,
+                "type": "VEC3",
+                "min": [ -1.5, 0.0, 2.0 ],
+                "max": [ 1.5, 1.0 ]
+            },
+            {
+                "bufferView": 0,
+                "componentType": 5123,
+                "count": 2,
+                "type": "SCALAR",
+                "min": [ 3 ],
+                "max": [ 65535 ]
+            },
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR" }
+        ]
+    }"#).unwrap();
+    let accessors = gltf.accessors().collect::<Vec<_>>();
+
+    assert_eq!(accessors[0].min_f32(), Some(vec![-1.5, 0.0, 2.0]));
+    assert_eq!(accessors[0].min_i64(), None);
+    // Too few components for a VEC3.
+    assert_eq!(accessors[0].max_f32(), None);
+
+    assert_eq!(accessors[1].min_i64(), Some(vec![3]));
+    assert_eq!(accessors[1].max_i64(), Some(vec![65535]));
+    assert_eq!(accessors[1].max_f32(), Some(vec![65535.0]));
+
+    assert_eq!(accessors[2].min_f32(), None);
+    assert_eq!(accessors[2].max_i64(), None);
+}
+
+#[test]
+fn test_accessor_data() {
+    let (document, buffers, _) = gltf::import("tests/minimal_accessor_min_max.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let accessors = document.accessors().collect::<Vec<_>>();
+
+    // Three `u16` indices at the start of the buffer.
+    assert_eq!(accessors[0].data(get_buffer_data), Some(&buffers[0][..6]));
+
+    // Three `f32` positions in the second buffer view.
+    let data = accessors[1].data(get_buffer_data).unwrap();
+    assert_eq!(data, &buffers[0][8..44]);
+
+    // Missing buffer data is reported as `None`.
+    assert!(accessors[1].data(|_| None).is_none());
+    assert!(accessors[1].data(|_| Some(&buffers[0][..40])).is_none());
+}
+
+#[test]
+fn test_accessor_read_vec3_f32() {
+    let (document, buffers, _) = gltf::import("tests/minimal_accessor_min_max.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    let accessors = document.accessors().collect::<Vec<_>>();
+
+    let positions = accessors[1].read_vec3_f32(get_buffer_data).unwrap();
+    assert_eq!(positions, [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+
+    // The indices are `u16` scalars.
+    assert_eq!(accessors[0].read_vec3_f32(get_buffer_data), None);
+}
+
+#[test]
+fn test_accessor_iter_type_mismatch() {
+    let (document, buffers, _) = gltf::import("tests/minimal_accessor_min_max.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let accessors = document.accessors().collect::<Vec<_>>();
+
+    assert!(gltf::accessor::Iter::<[f32; 3]>::new(accessors[1].clone(), get_buffer_data).is_some());
+
+    // Same size as `[f32; 3]` but a different component type.
+    assert!(gltf::accessor::Iter::<[u32; 3]>::new(accessors[1].clone(), get_buffer_data).is_none());
+
+    // Same size as the `u16` indices but different dimensions.
+    assert!(gltf::accessor::Iter::<[u8; 2]>::new(accessors[0].clone(), get_buffer_data).is_none());
+}
+
+#[test]
+fn test_buffer_view_strided_chunks() {
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 12 } ],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 12 },
+            { "buffer": 0, "byteLength": 12, "byteStride": 4 },
+            { "buffer": 0, "byteLength": 10, "byteStride": 4 }
+        ]
+    }"#).unwrap();
+    let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    let views = gltf.views().collect::<Vec<_>>();
+
+    // Tightly packed.
+    assert_eq!(views[0].stride(), None);
+    let chunks = views[0].strided_chunks(&data, 2).collect::<Vec<_>>();
+    assert_eq!(chunks, [&[0, 1][..], &[2, 3], &[4, 5], &[6, 7], &[8, 9], &[10, 11]]);
+
+    // Interleaved, visiting the second attribute of each vertex.
+    assert_eq!(views[1].stride(), Some(4));
+    let chunks = views[1].strided_chunks(&data[2..], 2).collect::<Vec<_>>();
+    assert_eq!(chunks, [&[2, 3][..], &[6, 7], &[10, 11]]);
+
+    // The last element does not need padding up to the stride.
+    let chunks = views[2].strided_chunks(&data[..10], 2).collect::<Vec<_>>();
+    assert_eq!(chunks, [&[0, 1][..], &[4, 5], &[8, 9]]);
+    assert_eq!(views[2].strided_chunks(&data[..1], 2).count(), 0);
+}
+
+#[test]
+fn test_default_scene() {
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "scenes": [ { "nodes": [] } ]
+    }"#).unwrap();
+    assert!(gltf.default_scene().is_none());
+
+    // Unvalidated documents may declare a default scene that does not exist.
+    let gltf = gltf::Gltf::from_slice_without_validation(br#"{
+        "asset": { "version": "2.0" },
+        "scene": 1,
+        "scenes": [ { "nodes": [] } ]
+    }"#).unwrap();
+    assert!(gltf.default_scene().is_none());
+}
+
+#[test]
+fn test_texture_default_sampler() {
+    use gltf::texture::{MagFilter, WrappingMode};
+
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "texture.png" } ],
+        "samplers": [ { "magFilter": 9728, "wrapS": 33071 } ],
+        "textures": [ { "source": 0 }, { "sampler": 0, "source": 0 } ]
+    }"#).unwrap();
+    let textures = gltf.textures().collect::<Vec<_>>();
+
+    // Textures without a sampler use repeat wrapping and automatic filtering.
+    let sampler = textures[0].sampler();
+    assert_eq!(sampler.index(), None);
+    assert_eq!(sampler.mag_filter(), None);
+    assert_eq!(sampler.min_filter(), None);
+    assert_eq!(sampler.wrap_s(), WrappingMode::Repeat);
+    assert_eq!(sampler.wrap_t(), WrappingMode::Repeat);
+
+    let sampler = textures[1].sampler();
+    assert_eq!(sampler.index(), Some(0));
+    assert_eq!(sampler.mag_filter(), Some(MagFilter::Nearest));
+    assert_eq!(sampler.wrap_s(), WrappingMode::ClampToEdge);
+}
+
+#[test]
+fn test_texture_without_source() {
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "images": [ { "uri": "texture.png" } ],
+        "textures": [ { "source": 0 }, {} ]
+    }"#).unwrap();
+    let textures = gltf.textures().collect::<Vec<_>>();
+    assert_eq!(textures[0].source().map(|image| image.index()), Some(0));
+    assert!(textures[1].source().is_none());
+}
+
+#[test]
+fn test_animation_channel_sampler() {
+    let gltf = gltf::Gltf::from_slice_without_validation(br#"{
+        "asset": { "version": "2.0" },
+        "samplers": [ { "magFilter": 9728 } ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR" },
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3" }
+        ],
+        "animations": [
+            {
+                "channels": [
+                    { "sampler": 1, "target": { "node": 0, "path": "scale" } },
+                    { "sampler": 0, "target": { "node": 0, "path": "translation" } }
+                ],
+                "samplers": [
+                    { "input": 0, "output": 1 },
+                    { "input": 0, "output": 2, "interpolation": "STEP" }
+                ]
+            }
+        ]
+    }"#).unwrap();
+    let animation = gltf.animations().next().unwrap();
+    let samplers = animation.channels()
+        .map(|channel| {
+            let sampler = channel.sampler();
+            (sampler.output().index(), sampler.interpolation())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(samplers, [
+        (2, gltf::animation::Interpolation::Step),
+        (1, gltf::animation::Interpolation::Linear),
+    ]);
+}
+
+#[test]
+fn test_check_required_extensions() {
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_lights_punctual", "KHR_draco_mesh_compression" ],
+        "extensionsRequired": [ "KHR_draco_mesh_compression" ]
+    }"#).unwrap();
+    match gltf.check_required_extensions(&[]) {
+        Err(gltf::Error::UnsupportedExtension(name)) => assert_eq!(name, "KHR_draco_mesh_compression"),
+        other => panic!("expected unsupported extension, found {:?}", other),
+    }
+    assert!(gltf.check_required_extensions(&["KHR_draco_mesh_compression"]).is_ok());
+}
+
+#[test]
+fn test_primitive_default_material() {
+    use gltf::material::AlphaMode;
+
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "buffers": [ { "byteLength": 36 } ],
+        "bufferViews": [ { "buffer": 0, "byteLength": 36 } ],
+        "accessors": [
+            {
+                "bufferView": 0,
+                "componentType": 5126,
+                "count": 3,
+                "type": "VEC3",
+                "min": [ 0.0, 0.0, 0.0 ],
+                "max": [ 1.0, 1.0, 0.0 ]
+            }
+        ],
+        "meshes": [ { "primitives": [ { "attributes": { "POSITION": 0 } } ] } ]
+    }"#).unwrap();
+    let mesh = gltf.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let material = primitive.material();
+
+    // Primitives without a material use the default metallic-roughness material.
+    assert_eq!(material.index(), None);
+    assert_eq!(material.alpha_mode(), AlphaMode::Opaque);
+    assert_eq!(material.alpha_cutoff(), 0.5);
+    assert!(!material.double_sided());
+    let pbr = material.pbr_metallic_roughness();
+    assert_eq!(pbr.base_color_factor(), [1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(pbr.metallic_factor(), 1.0);
+    assert_eq!(pbr.roughness_factor(), 1.0);
+    assert_eq!(material.emissive_factor(), [0.0, 0.0, 0.0]);
+
+    let json = gltf.document.into_json();
+    assert!(json.meshes[0].primitives[0].material.is_none());
+    let string = gltf::json::serialize::to_string(&json).unwrap();
+    assert!(!string.contains("\"material\""));
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+#[test]
+fn test_texture_transform() {
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_texture_transform" ],
+        "images": [ { "uri": "texture.png" } ],
+        "textures": [ { "source": 0 } ],
+        "materials": [
+            {
+                "pbrMetallicRoughness": {
+                    "baseColorTexture": {
+                        "index": 0,
+                        "extensions": {
+                            "KHR_texture_transform": { "offset": [ 0.5, 0.0 ], "scale": [ 2.0, 2.0 ] }
+                        }
+                    }
+                },
+                "emissiveTexture": { "index": 0 }
+            }
+        ]
+    }"#).unwrap();
+    let material = gltf.materials().next().unwrap();
+    let info = material.pbr_metallic_roughness().base_color_texture().unwrap();
+    let transform = info.texture_transform().unwrap();
+    assert_eq!(transform.offset(), [0.5, 0.0]);
+    assert_eq!(transform.rotation(), 0.0);
+    assert_eq!(transform.scale(), [2.0, 2.0]);
+    assert_eq!(transform.tex_coord(), None);
+    assert!(material.emissive_texture().unwrap().texture_transform().is_none());
+}
+
+#[cfg(feature = "KHR_lights_punctual")]
+#[test]
+fn test_lights_punctual() {
+    use gltf::khr_lights_punctual::Kind;
+
+    let gltf = gltf::Gltf::from_slice(br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": [ "KHR_lights_punctual" ],
+        "extensions": {
+            "KHR_lights_punctual": {
+                "lights": [
+                    { "type": "directional", "color": [ 1.0, 0.5, 0.0 ], "intensity": 3.0 },
+                    { "type": "spot", "range": 10.0, "spot": { "innerConeAngle": 0.25 } }
+                ]
+            }
+        },
+        "nodes": [
+            {},
+            { "extensions": { "KHR_lights_punctual": { "light": 1 } } }
+        ]
+    }"#).unwrap();
+    let lights = gltf.lights().unwrap().collect::<Vec<_>>();
+    assert_eq!(lights.len(), 2);
+
+    assert!(matches!(lights[0].kind(), Kind::Directional));
+    assert_eq!(lights[0].color(), [1.0, 0.5, 0.0]);
+    assert_eq!(lights[0].intensity(), 3.0);
+    assert_eq!(lights[0].range(), None);
+
+    match lights[1].kind() {
+        Kind::Spot { inner_cone_angle, outer_cone_angle } => {
+            assert_eq!(inner_cone_angle, 0.25);
+            assert_eq!(outer_cone_angle, std::f32::consts::FRAC_PI_4);
+        },
+        _ => panic!("expected a spot light"),
+    }
+    assert_eq!(lights[1].color(), [1.0, 1.0, 1.0]);
+    assert_eq!(lights[1].intensity(), 1.0);
+    assert_eq!(lights[1].range(), Some(10.0));
+
+    let nodes = gltf.nodes().collect::<Vec<_>>();
+    assert!(nodes[0].light().is_none());
+    assert_eq!(nodes[1].light().map(|light| light.index()), Some(1));
+
+    // Documents without the extension have no lights.
+    let gltf = gltf::Gltf::from_slice(br#"{ "asset": { "version": "2.0" } }"#).unwrap();
+    assert!(gltf.lights().is_none());
+}
+
+#[test]
+fn test_document_export() {
+    let json = fs::read_to_string("tests/minimal_accessor_min_max.gltf").unwrap();
+    let gltf = gltf::Gltf::from_slice(json.as_bytes()).unwrap();
+    let string = gltf.to_string().unwrap();
+
+    // The exported JSON imports to an equivalent document.
+    let reimported = gltf::Gltf::from_slice(string.as_bytes()).unwrap();
+    assert_eq!(reimported.to_string().unwrap(), string);
+    let expected = gltf::json::Root::from_str(&json).unwrap().to_value().unwrap();
+    assert_eq!(reimported.document.into_json().to_value().unwrap(), expected);
+
+    let mut bytes = Vec::new();
+    gltf.to_writer(&mut bytes).unwrap();
+    assert_eq!(bytes, string.as_bytes());
+
+    // Writer failures are reported as serialization errors.
+    let mut full = [0u8; 8];
+    match gltf.to_writer(&mut full[..]) {
+        Err(gltf::Error::Serialize(error)) => assert!(error.is_io()),
+        other => panic!("expected serialization error, found {:?}", other),
+    }
+
+    // Invalid documents are not exported.
+    let mut root = gltf.document.clone().into_json();
+    root.scene = Some(gltf::json::Index::new(1));
+    let document = gltf::Document::from_json_without_validation(root);
+    match document.to_string() {
+        Err(gltf::Error::Validation(errors)) => assert_eq!(errors.len(), 1),
+        other => panic!("expected validation error, found {:?}", other),
+    }
+    let mut bytes = Vec::new();
+    assert!(document.to_writer(&mut bytes).is_err());
+    assert!(bytes.is_empty());
+    assert!(document.to_string_pretty().is_err());
+    assert!(document.to_writer_pretty(&mut bytes).is_err());
+    assert!(bytes.is_empty());
+
+    // Pretty-printed output describes the same document.
+    let pretty = gltf.to_string_pretty().unwrap();
+    assert!(pretty.contains('\n'));
+    let reimported = gltf::Gltf::from_slice(pretty.as_bytes()).unwrap();
+    assert_eq!(reimported.to_string().unwrap(), string);
+    let mut bytes = Vec::new();
+    gltf.to_writer_pretty(&mut bytes).unwrap();
+    assert_eq!(bytes, pretty.as_bytes());
+}
+
+#[test]
+fn test_document_to_glb() {
+    let gltf = gltf::Gltf::open("tests/minimal_accessor_min_max.gltf").unwrap();
+    let bin = gltf.buffers().next().unwrap().decode_uri().unwrap();
+
+    // Buffer 0 must describe the BIN chunk.
+    match gltf.to_glb(&bin) {
+        Err(gltf::Error::Validation(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0.as_str(), "buffers[0].uri");
+        },
+        other => panic!("expected validation error, found {:?}", other),
+    }
+    let mut root = gltf.document.clone().into_json();
+    root.buffers[0].uri = None;
+    let document = gltf::Document::from_json(root).unwrap();
+    match document.to_glb(&bin[..40]) {
+        Err(gltf::Error::Validation(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0.as_str(), "buffers[0].byteLength");
+        },
+        other => panic!("expected validation error, found {:?}", other),
+    }
+
+    let glb = document.to_glb(&bin).unwrap();
+    assert_eq!(&glb[..4], b"glTF");
+    assert_eq!(glb.len() % 4, 0);
+    assert_eq!(&glb[8..12], &(glb.len() as u32).to_le_bytes());
+
+    let (imported, buffers, _) = gltf::import_slice(&glb).unwrap();
+    assert_eq!(&*buffers[0], &bin[..]);
+    assert_eq!(imported.to_string().unwrap(), document.to_string().unwrap());
+}
+
+#[test]
+fn test_error_source() {
+    use std::error::Error;
+
+    fn load(json: &[u8]) -> Result<gltf::Gltf, Box<dyn Error>> {
+        Ok(gltf::Gltf::from_slice(json)?)
+    }
+
+    let error = load(b"{ \"asset\": ").unwrap_err();
+    let source = error.source().expect("JSON errors have a source");
+    assert!(source.downcast_ref::<gltf::json::Error>().unwrap().is_eof());
+    assert_eq!(error.to_string(), source.to_string());
+
+    let error = load(br#"{ "asset": { "version": "2.0" }, "scene": 0 }"#).unwrap_err();
+    assert!(error.source().is_none());
+    assert_eq!(error.to_string(), "invalid glTF: scene: Index out of bounds (have 0);");
+
+    let error = gltf::Gltf::from_slice(b"glTF\x01\0\0\0\x0c\0\0\0").unwrap_err();
+    assert_eq!(error.to_string(), "unsupported version 1");
+    assert!(error.source().unwrap().source().is_none());
+}
+
+#[test]
+fn test_unsupported_version() {
+    let json = br#"{
+        "asset": { "version": "1.0" },
+        "meshes": { "mesh_0": { "primitives": [] } },
+        "scene": "defaultScene"
+    }"#;
+    match gltf::Gltf::from_slice(json) {
+        Err(gltf::Error::UnsupportedVersion(version)) => assert_eq!(version, "1.0"),
+        other => panic!("expected unsupported version, found {:?}", other.map(|_| ())),
+    }
+    match gltf::Gltf::from_reader(io::Cursor::new(&json[..])) {
+        Err(gltf::Error::UnsupportedVersion(version)) => assert_eq!(version, "1.0"),
+        other => panic!("expected unsupported version, found {:?}", other.map(|_| ())),
+    }
+    // A plain stream cannot be re-read to find the version after a failure.
+    match gltf::Document::from_reader(&json[..]) {
+        Err(gltf::Error::Deserialize(_)) => {},
+        other => panic!("expected deserialize error, found {:?}", other.map(|_| ())),
+    }
+    match gltf::Document::from_reader(&br#"{ "asset": { "version": "1.0" } }"#[..]) {
+        Err(gltf::Error::UnsupportedVersion(version)) => assert_eq!(version, "1.0"),
+        other => panic!("expected unsupported version, found {:?}", other.map(|_| ())),
+    }
+
+    // glTF 1.0 files are rejected by every loader.
+    match gltf::Gltf::open("tests/minimal_gltf_1_0.gltf") {
+        Err(gltf::Error::UnsupportedVersion(version)) => assert_eq!(version, "1.0"),
+        other => panic!("expected unsupported version, found {:?}", other.map(|_| ())),
+    }
+    match gltf::import("tests/minimal_gltf_1_0.gltf") {
+        Err(gltf::Error::UnsupportedVersion(version)) => assert_eq!(version, "1.0"),
+        other => panic!("expected unsupported version, found {:?}", other.map(|_| ())),
+    }
+
+    // Any glTF 2.x version is accepted, including a bare major version.
+    assert!(gltf::Gltf::from_slice(br#"{ "asset": { "version": "2" } }"#).is_ok());
+    assert!(gltf::Gltf::from_slice(br#"{ "asset": { "version": "2.1" } }"#).is_ok());
+
+    // Assets that require a newer version than glTF 2.0 are rejected.
+    let json = br#"{ "asset": { "version": "2.1", "minVersion": "2.1" } }"#;
+    match gltf::Gltf::from_slice(json) {
+        Err(error @ gltf::Error::UnsupportedVersion(_)) => {
+            assert_eq!(error.to_string(), "unsupported glTF version 2.1");
+        },
+        other => panic!("expected unsupported version, found {:?}", other.map(|_| ())),
+    }
+
+    // A supported minVersion is kept on export.
+    let gltf = gltf::Gltf::from_slice(br#"{ "asset": { "version": "2.1", "minVersion": "2.0" } }"#).unwrap();
+    assert!(gltf.to_string().unwrap().contains(r#""minVersion":"2.0""#));
+    let root = gltf.document.into_json();
+    assert_eq!(root.asset.min_version.as_ref().map(String::as_str), Some("2.0"));
+}